@@ -0,0 +1,34 @@
+//! Compares `generic_from`, which moves a `GenericArray` into a vector's storage in one go, with
+//! building the same vector element by element.
+//!
+//! If `generic_from` copied per element, the two would take about the same time; instead it
+//! should match the cost of a single `memcpy` of the array.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use generic_arrayvec::generic_array::sequence::GenericSequence;
+use generic_arrayvec::generic_array::GenericArray;
+use generic_arrayvec::typenum::U4096;
+use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+
+fn bench_generic_from(c: &mut Criterion) {
+    let arr: GenericArray<u64, U4096> = GenericArray::generate(|i| i as u64);
+    let mut group = c.benchmark_group("from GenericArray<u64, U4096>");
+
+    group.bench_function("generic_from", |b| {
+        b.iter(|| GenericArrayVec::generic_from(black_box(arr)))
+    });
+    group.bench_function("push each element", |b| {
+        b.iter(|| {
+            let mut vec = GenericArrayVec::<u64, U4096>::new();
+            for element in black_box(arr) {
+                vec.push(element);
+            }
+            vec
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_generic_from);
+criterion_main!(benches);
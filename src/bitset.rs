@@ -0,0 +1,199 @@
+use crate::private::Sealed;
+use core::fmt::{self, Debug};
+use core::iter::FusedIterator;
+use core::ops::{Add, Div};
+use generic_array::typenum::{Quot, Sum, Unsigned, U7, U8};
+use generic_array::{ArrayLength, GenericArray};
+
+/// Implemented for every `typenum` number of bits, giving the number of bytes needed to store
+/// them.
+///
+/// That's `N / 8`, rounded up, computed at the type level. It's implemented for all `N`, so you
+/// shouldn't need to name it, except to write `N: BitCapacity` in generic code.
+pub trait BitCapacity: Unsigned {
+    /// `N` divided by 8, rounded up.
+    type Bytes: ArrayLength<u8>;
+    fn _sealed(_: Sealed);
+}
+
+impl<N> BitCapacity for N
+where
+    N: Unsigned + Add<U7>,
+    Sum<N, U7>: Div<U8>,
+    Quot<Sum<N, U7>, U8>: ArrayLength<u8>,
+{
+    type Bytes = Quot<Sum<N, U7>, U8>;
+    fn _sealed(_: Sealed) {}
+}
+
+/// A set of `N` bits, stored in `N / 8` bytes, rounded up.
+///
+/// The bits are indexed from `0` up to `N`, with bit `i` stored in byte `i / 8`, at position
+/// `i % 8` counting from the least significant bit. Any bits in the last byte past `N` are always
+/// clear.
+///
+/// ```rust
+/// use generic_arrayvec::bitset::GenericBitSet;
+/// use generic_arrayvec::typenum::U12;
+///
+/// let mut flags = GenericBitSet::<U12>::new();
+/// flags.set(3);
+/// flags.set(11);
+///
+/// assert!(flags.get(3));
+/// assert!(!flags.get(4));
+/// assert_eq!(flags.count_ones(), 2);
+/// assert!(flags.iter_ones().eq([3, 11].iter().copied()));
+/// assert_eq!(flags.as_bytes().len(), 2);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct GenericBitSet<N>
+where
+    N: BitCapacity,
+{
+    bytes: GenericArray<u8, N::Bytes>,
+}
+
+impl<N> GenericBitSet<N>
+where
+    N: BitCapacity,
+{
+    /// Creates a new bit set with every bit clear.
+    pub fn new() -> Self {
+        GenericBitSet {
+            bytes: GenericArray::default(),
+        }
+    }
+
+    /// Returns the number of bits in the set, which is `N`.
+    pub fn capacity(&self) -> usize {
+        N::USIZE
+    }
+
+    /// Returns whether bit `index` is set.
+    ///
+    /// **Panics** if `index >= N`.
+    pub fn get(&self, index: usize) -> bool {
+        let (byte, mask) = Self::locate(index);
+        self.bytes[byte] & mask != 0
+    }
+
+    /// Sets bit `index`.
+    ///
+    /// **Panics** if `index >= N`.
+    pub fn set(&mut self, index: usize) {
+        let (byte, mask) = Self::locate(index);
+        self.bytes[byte] |= mask;
+    }
+
+    /// Clears bit `index`.
+    ///
+    /// **Panics** if `index >= N`.
+    pub fn clear(&mut self, index: usize) {
+        let (byte, mask) = Self::locate(index);
+        self.bytes[byte] &= !mask;
+    }
+
+    /// Clears every bit.
+    pub fn clear_all(&mut self) {
+        self.bytes = GenericArray::default();
+    }
+
+    /// Returns the number of bits that are set.
+    pub fn count_ones(&self) -> usize {
+        self.bytes
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns an iterator over the indices of the set bits, in increasing order.
+    pub fn iter_ones(&self) -> Ones<'_> {
+        Ones {
+            bytes: &self.bytes,
+            base: 0,
+            next_base: 0,
+            current: 0,
+        }
+    }
+
+    /// Returns the bytes the bits are stored in.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    // Returns the byte index and bit mask for bit `index`, checking that it's in range. Neither
+    // computation can overflow, unlike `index + 7` and the like.
+    fn locate(index: usize) -> (usize, u8) {
+        assert!(
+            index < N::USIZE,
+            "bit index {} is out of range for a bit set of {} bits",
+            index,
+            N::USIZE
+        );
+
+        (index / 8, 1 << (index % 8))
+    }
+}
+
+impl<N> Copy for GenericBitSet<N>
+where
+    N: BitCapacity,
+    <N::Bytes as ArrayLength<u8>>::ArrayType: Copy,
+{
+}
+
+impl<N> Default for GenericBitSet<N>
+where
+    N: BitCapacity,
+{
+    fn default() -> Self {
+        GenericBitSet::new()
+    }
+}
+
+impl<N> Debug for GenericBitSet<N>
+where
+    N: BitCapacity,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter_ones()).finish()
+    }
+}
+
+/// An iterator over the indices of the set bits in a [`GenericBitSet`], in increasing order.
+///
+/// Returned by [`GenericBitSet::iter_ones`].
+#[derive(Debug, Clone)]
+pub struct Ones<'a> {
+    // The bytes not yet loaded into `current`.
+    bytes: &'a [u8],
+    // The bit index of the lowest bit in `current`.
+    base: usize,
+    // The bit index of the lowest bit in `bytes[0]`.
+    next_base: usize,
+    // The bits of the current byte not yet yielded.
+    current: u8,
+}
+
+impl<'a> Iterator for Ones<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            let (&next, rest) = self.bytes.split_first()?;
+            self.base = self.next_base;
+            self.next_base += 8;
+            self.current = next;
+            self.bytes = rest;
+        }
+
+        let bit = self.current.trailing_zeros() as usize;
+        // Clear the lowest set bit.
+        self.current &= self.current - 1;
+
+        Some(self.base + bit)
+    }
+}
+
+impl<'a> FusedIterator for Ones<'a> {}
@@ -0,0 +1,131 @@
+use crate::{ArrayvecStorage, Capacity, GenericArrayVec};
+use arrayvec::{Array, CapacityError};
+use core::fmt::{self, Debug};
+
+/// A builder that fills a [`GenericArrayVec`] with exactly the number of elements it was told to
+/// expect.
+///
+/// Returned by [`GenericArrayVecExt::with_reported_len`](crate::GenericArrayVecExt::with_reported_len),
+/// which has already checked that the expected length fits within the capacity. This suits reading
+/// a length from a header and then exactly that many elements, without reaching for
+/// [`set_len`](arrayvec::ArrayVec::set_len).
+///
+/// ```rust
+/// use generic_arrayvec::typenum::U8;
+/// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+///
+/// let mut builder = GenericArrayVec::<u8, U8>::with_reported_len(2).unwrap();
+/// builder.push(10).unwrap();
+/// builder.push(20).unwrap();
+/// assert!(builder.push(30).is_err());
+///
+/// let mut builder = GenericArrayVec::<u8, U8>::with_reported_len(2).unwrap();
+/// builder.push(10).unwrap();
+/// builder.push(20).unwrap();
+/// assert_eq!(&builder.finish().unwrap()[..], &[10, 20]);
+/// ```
+pub struct ExactLenBuilder<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    vec: GenericArrayVec<T, N>,
+    expected: usize,
+    // The number of pushes so far, including rejected ones.
+    pushed: usize,
+}
+
+impl<T, N> ExactLenBuilder<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    pub(crate) fn new(expected: usize) -> Result<Self, CapacityError> {
+        if expected > N::USIZE {
+            return Err(CapacityError::new(()));
+        }
+
+        Ok(ExactLenBuilder {
+            vec: GenericArrayVec::new(),
+            expected,
+            pushed: 0,
+        })
+    }
+
+    /// Appends the next element.
+    ///
+    /// **Errors** if all the expected elements have already been pushed, returning the element.
+    /// The builder remembers this, so [`finish`](ExactLenBuilder::finish) fails too.
+    pub fn push(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        self.pushed = self.pushed.saturating_add(1);
+        if self.pushed > self.expected {
+            return Err(CapacityError::new(element));
+        }
+
+        self.vec.push(element);
+        Ok(())
+    }
+
+    /// Returns the number of elements the builder was told to expect.
+    pub fn expected_len(&self) -> usize {
+        self.expected
+    }
+
+    /// Returns how many more elements need to be pushed before the builder can finish.
+    pub fn remaining(&self) -> usize {
+        self.expected.saturating_sub(self.pushed)
+    }
+
+    /// Returns the finished vector.
+    ///
+    /// **Errors** if the number of elements pushed isn't exactly the expected length, whether
+    /// there were too few or too many. The elements pushed so far are dropped in that case.
+    pub fn finish(self) -> Result<GenericArrayVec<T, N>, LenMismatch> {
+        if self.pushed == self.expected {
+            Ok(self.vec)
+        } else {
+            Err(LenMismatch {
+                expected: self.expected,
+                actual: self.pushed,
+            })
+        }
+    }
+}
+
+impl<T, N> Debug for ExactLenBuilder<T, N>
+where
+    T: Debug,
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExactLenBuilder")
+            .field("vec", &self.vec)
+            .field("expected", &self.expected)
+            .field("pushed", &self.pushed)
+            .finish()
+    }
+}
+
+/// The error returned by [`ExactLenBuilder::finish`] when the wrong number of elements was pushed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LenMismatch {
+    /// The number of elements the builder expected.
+    pub expected: usize,
+    /// The number of elements actually pushed, including any rejected for being over the expected
+    /// length.
+    pub actual: usize,
+}
+
+impl fmt::Display for LenMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected exactly {} elements, but {} were pushed",
+            self.expected, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LenMismatch {}
@@ -0,0 +1,571 @@
+#[cfg(feature = "base64")]
+use crate::GenericArrayString;
+use crate::{ArrayvecStorage, Capacity, GenericArrayVec};
+#[cfg(all(feature = "hex", feature = "alloc"))]
+use alloc::string::String;
+use arrayvec::{Array, CapacityError};
+#[cfg(feature = "base64")]
+use core::fmt;
+#[cfg(feature = "hex")]
+pub use hex::FromHexError;
+
+/// Extension trait for [`GenericArrayVec<u8, N>`].
+///
+/// See its impl on [`GenericArrayVec`] for more info.
+///
+/// ```rust
+/// use generic_arrayvec::bytes::GenericArrayVecBytesExt;
+/// use generic_arrayvec::typenum::U8;
+/// use generic_arrayvec::GenericArrayVec;
+///
+/// let mut buf = GenericArrayVec::<u8, U8>::new();
+/// buf.push_u16_be(0x0102).unwrap();
+/// buf.push_u32_le(0x0304_0506).unwrap();
+///
+/// assert_eq!(&buf[..], &[0x01, 0x02, 0x06, 0x05, 0x04, 0x03]);
+/// assert!(buf.push_u32_le(0).is_err());
+/// ```
+pub trait GenericArrayVecBytesExt<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+{
+    fn push_u16_le(&mut self, value: u16) -> Result<(), CapacityError<u16>>;
+
+    fn push_u16_be(&mut self, value: u16) -> Result<(), CapacityError<u16>>;
+
+    fn push_u32_le(&mut self, value: u32) -> Result<(), CapacityError<u32>>;
+
+    fn push_u32_be(&mut self, value: u32) -> Result<(), CapacityError<u32>>;
+
+    fn push_u64_le(&mut self, value: u64) -> Result<(), CapacityError<u64>>;
+
+    fn push_u64_be(&mut self, value: u64) -> Result<(), CapacityError<u64>>;
+
+    fn push_u128_le(&mut self, value: u128) -> Result<(), CapacityError<u128>>;
+
+    fn push_u128_be(&mut self, value: u128) -> Result<(), CapacityError<u128>>;
+
+    fn from_u16_le(values: &[u16]) -> Result<GenericArrayVec<u8, N>, CapacityError>;
+
+    fn from_u16_be(values: &[u16]) -> Result<GenericArrayVec<u8, N>, CapacityError>;
+
+    fn from_u32_le(values: &[u32]) -> Result<GenericArrayVec<u8, N>, CapacityError>;
+
+    fn from_u32_be(values: &[u32]) -> Result<GenericArrayVec<u8, N>, CapacityError>;
+
+    fn from_u64_le(values: &[u64]) -> Result<GenericArrayVec<u8, N>, CapacityError>;
+
+    fn from_u64_be(values: &[u64]) -> Result<GenericArrayVec<u8, N>, CapacityError>;
+
+    fn from_u128_le(values: &[u128]) -> Result<GenericArrayVec<u8, N>, CapacityError>;
+
+    fn from_u128_be(values: &[u128]) -> Result<GenericArrayVec<u8, N>, CapacityError>;
+
+    fn from_be_uint(value: u128) -> Result<GenericArrayVec<u8, N>, CapacityError>;
+
+    fn to_be_uint(&self) -> Option<u128>;
+
+    fn find_byte(&self, needle: u8) -> Option<usize>;
+
+    fn rfind_byte(&self, needle: u8) -> Option<usize>;
+
+    #[cfg(feature = "subtle")]
+    fn ct_eq(&self, other: &[u8]) -> bool;
+
+    #[cfg(feature = "hex")]
+    fn from_hex(s: &str) -> Result<GenericArrayVec<u8, N>, FromHexError>;
+
+    #[cfg(all(feature = "hex", feature = "alloc"))]
+    fn to_hex_string(&self) -> String;
+
+    #[cfg(feature = "base64")]
+    fn from_base64(s: &str) -> Result<GenericArrayVec<u8, N>, FromBase64Error>;
+
+    #[cfg(feature = "base64")]
+    fn to_base64<O>(&self) -> Result<GenericArrayString<O>, CapacityError>
+    where
+        O: Capacity<u8>,
+        ArrayvecStorage<u8, O>: Array<Item = u8>,
+        O::ArrayType: Copy;
+
+    #[cfg(feature = "crc")]
+    fn crc32(&self) -> u32;
+
+    #[cfg(feature = "fnv")]
+    fn fnv1a(&self) -> u64;
+}
+
+macro_rules! byte_order_impls {
+    ($($int:ident: $push:ident, $from:ident, $to_bytes:ident, $order:literal;)*) => {$(
+        #[doc = concat!("Appends `value` as ", $order, " bytes.")]
+        ///
+        /// **Errors** if the bytes don't fit; nothing is appended in that case.
+        fn $push(&mut self, value: $int) -> Result<(), CapacityError<$int>> {
+            self.try_extend_from_slice(&value.$to_bytes())
+                .map_err(|_| CapacityError::new(value))
+        }
+
+        #[doc = concat!("Creates a byte vector holding each of `values` as ", $order, " bytes.")]
+        ///
+        /// **Errors** if the bytes don't fit within the capacity.
+        fn $from(values: &[$int]) -> Result<GenericArrayVec<u8, N>, CapacityError> {
+            let mut vec = GenericArrayVec::new();
+            for &value in values {
+                vec.try_extend_from_slice(&value.$to_bytes())?;
+            }
+
+            Ok(vec)
+        }
+    )*};
+}
+
+impl<N> GenericArrayVecBytesExt<N> for GenericArrayVec<u8, N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+{
+    byte_order_impls! {
+        u16: push_u16_le, from_u16_le, to_le_bytes, "little-endian";
+        u16: push_u16_be, from_u16_be, to_be_bytes, "big-endian";
+        u32: push_u32_le, from_u32_le, to_le_bytes, "little-endian";
+        u32: push_u32_be, from_u32_be, to_be_bytes, "big-endian";
+        u64: push_u64_le, from_u64_le, to_le_bytes, "little-endian";
+        u64: push_u64_be, from_u64_be, to_be_bytes, "big-endian";
+        u128: push_u128_le, from_u128_le, to_le_bytes, "little-endian";
+        u128: push_u128_be, from_u128_be, to_be_bytes, "big-endian";
+    }
+
+    /// Creates a byte vector holding `value` as a big-endian unsigned integer, in as few bytes as
+    /// possible.
+    ///
+    /// Leading zero bytes are left out, so zero is encoded as an empty vector. For a fixed-width
+    /// encoding, follow this with
+    /// [`left_pad_with(0)`](crate::GenericArrayVecExt::left_pad_with).
+    ///
+    /// **Errors** if the bytes don't fit within the capacity.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::bytes::GenericArrayVecBytesExt;
+    /// use generic_arrayvec::typenum::{U1, U4};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::<u8, U4>::from_be_uint(0x01_0203).unwrap();
+    /// assert_eq!(&vec[..], &[1, 2, 3]);
+    ///
+    /// vec.left_pad_with(0);
+    /// assert_eq!(&vec[..], &[0, 1, 2, 3]);
+    ///
+    /// assert!(GenericArrayVec::<u8, U1>::from_be_uint(0x100).is_err());
+    /// ```
+    fn from_be_uint(value: u128) -> Result<GenericArrayVec<u8, N>, CapacityError> {
+        let bytes = value.to_be_bytes();
+        let leading_zeros = (value.leading_zeros() / 8) as usize;
+
+        let mut vec = GenericArrayVec::new();
+        vec.try_extend_from_slice(&bytes[leading_zeros..])?;
+
+        Ok(vec)
+    }
+
+    /// Reads the bytes as a big-endian unsigned integer.
+    ///
+    /// Any number of leading zero bytes is accepted, so this reads back both the output of
+    /// [`from_be_uint`](GenericArrayVecBytesExt::from_be_uint) and fixed-width encodings. An empty
+    /// vector reads as zero.
+    ///
+    /// Returns `None` if the value doesn't fit in a `u128`.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::bytes::GenericArrayVecBytesExt;
+    /// use generic_arrayvec::typenum::U32;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([0, 0, 1, 0]);
+    /// assert_eq!(vec.to_be_uint(), Some(0x100));
+    ///
+    /// let mut big = GenericArrayVec::<u8, U32>::new();
+    /// big.push(1);
+    /// big.extend(core::iter::repeat(0).take(16));
+    /// assert_eq!(big.to_be_uint(), None);
+    /// ```
+    fn to_be_uint(&self) -> Option<u128> {
+        let start = self
+            .iter()
+            .position(|&byte| byte != 0)
+            .unwrap_or(self.len());
+        let significant = &self[start..];
+        if significant.len() > 16 {
+            return None;
+        }
+
+        let mut bytes = [0; 16];
+        bytes[16 - significant.len()..].copy_from_slice(significant);
+
+        Some(u128::from_be_bytes(bytes))
+    }
+
+    /// Returns the index of the first occurrence of `needle` in the vector, or `None` if it
+    /// doesn't occur.
+    ///
+    /// With the `memchr` feature, this uses the `memchr` crate's vectorized search; otherwise it's
+    /// a plain scan. Either way, only the `len` bytes in the vector are searched.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::bytes::GenericArrayVecBytesExt;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let line = GenericArrayVec::generic_from(*b"key: value\r\n");
+    ///
+    /// assert_eq!(line.find_byte(b':'), Some(3));
+    /// assert_eq!(line.find_byte(b'#'), None);
+    /// ```
+    fn find_byte(&self, needle: u8) -> Option<usize> {
+        #[cfg(feature = "memchr")]
+        return memchr::memchr(needle, self);
+
+        #[cfg(not(feature = "memchr"))]
+        return self.iter().position(|&byte| byte == needle);
+    }
+
+    /// Returns the index of the last occurrence of `needle` in the vector, or `None` if it
+    /// doesn't occur.
+    ///
+    /// Like [`find_byte`](GenericArrayVecBytesExt::find_byte), this uses the `memchr` crate if
+    /// the `memchr` feature is enabled.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::bytes::GenericArrayVecBytesExt;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let path = GenericArrayVec::generic_from(*b"a/b/c");
+    ///
+    /// assert_eq!(path.rfind_byte(b'/'), Some(3));
+    /// assert_eq!(path.rfind_byte(b'.'), None);
+    /// ```
+    fn rfind_byte(&self, needle: u8) -> Option<usize> {
+        #[cfg(feature = "memchr")]
+        return memchr::memrchr(needle, self);
+
+        #[cfg(not(feature = "memchr"))]
+        return self.iter().rposition(|&byte| byte == needle);
+    }
+
+    /// Returns `true` if the vector's contents equal `other`, comparing in constant time.
+    ///
+    /// The time taken depends only on the lengths involved, never on the bytes themselves, which
+    /// makes this suitable for comparing secrets such as MACs. If the lengths differ, this returns
+    /// `false` without looking at the contents.
+    ///
+    /// Requires the `subtle` feature.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::bytes::GenericArrayVecBytesExt;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let tag = GenericArrayVec::generic_from([0xde, 0xad, 0xbe, 0xef]);
+    ///
+    /// assert!(tag.ct_eq(&[0xde, 0xad, 0xbe, 0xef]));
+    /// assert!(!tag.ct_eq(&[0xde, 0xad, 0xbe, 0x00]));
+    /// assert!(!tag.ct_eq(&[0xde, 0xad]));
+    /// ```
+    #[cfg(feature = "subtle")]
+    fn ct_eq(&self, other: &[u8]) -> bool {
+        subtle::ConstantTimeEq::ct_eq(self.as_slice(), other).into()
+    }
+
+    /// Creates a byte vector from a string of hex digits, two per byte. Both upper and lower case
+    /// digits are accepted.
+    ///
+    /// **Errors** with:
+    ///
+    /// - [`FromHexError::OddLength`] if `s` has an odd number of digits,
+    /// - [`FromHexError::InvalidStringLength`] if the bytes don't fit within the capacity, or
+    /// - [`FromHexError::InvalidHexCharacter`] if `s` contains anything other than hex digits.
+    ///
+    /// Requires the `hex` feature.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::bytes::{FromHexError, GenericArrayVecBytesExt};
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::GenericArrayVec;
+    ///
+    /// let vec = GenericArrayVec::<u8, U4>::from_hex("c0FFee").unwrap();
+    /// assert_eq!(&vec[..], &[0xc0, 0xff, 0xee]);
+    ///
+    /// assert_eq!(GenericArrayVec::<u8, U4>::from_hex("abc"), Err(FromHexError::OddLength));
+    /// assert_eq!(
+    ///     GenericArrayVec::<u8, U4>::from_hex("0011223344"),
+    ///     Err(FromHexError::InvalidStringLength),
+    /// );
+    /// ```
+    #[cfg(feature = "hex")]
+    fn from_hex(s: &str) -> Result<GenericArrayVec<u8, N>, FromHexError> {
+        if s.len() % 2 != 0 {
+            return Err(FromHexError::OddLength);
+        }
+        let len = s.len() / 2;
+        if len > N::USIZE {
+            return Err(FromHexError::InvalidStringLength);
+        }
+
+        let mut vec = GenericArrayVec::new();
+        vec.extend(core::iter::repeat(0).take(len));
+        hex::decode_to_slice(s, &mut vec)?;
+
+        Ok(vec)
+    }
+
+    /// Returns the bytes as a string of lowercase hex digits, two per byte.
+    ///
+    /// Requires the `hex` and `alloc` features.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::bytes::GenericArrayVecBytesExt;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([0xc0, 0xff, 0xee]);
+    ///
+    /// assert_eq!(vec.to_hex_string(), "c0ffee");
+    /// ```
+    #[cfg(all(feature = "hex", feature = "alloc"))]
+    fn to_hex_string(&self) -> String {
+        let mut digits = alloc::vec![0; self.len() * 2];
+        hex::encode_to_slice(self, &mut digits).expect("the buffer holds two digits per byte");
+
+        String::from_utf8(digits).expect("hex digits are valid UTF-8")
+    }
+
+    /// Creates a byte vector from a base64 string, using the standard alphabet with padding.
+    ///
+    /// **Errors** with:
+    ///
+    /// - [`FromBase64Error::InvalidLength`] if the length of `s` isn't a multiple of 4,
+    /// - [`FromBase64Error::CapacityExceeded`] if the bytes don't fit within the capacity, or
+    /// - [`FromBase64Error::InvalidByte`] if `s` contains anything other than base64 digits
+    ///   followed by up to two `=`, or if it isn't in canonical form: the digit before the padding
+    ///   mustn't have any bits set beyond the last whole byte, so `"Zg=="` is accepted but `"Zh=="`
+    ///   isn't.
+    ///
+    /// Requires the `base64` feature.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::bytes::{FromBase64Error, GenericArrayVecBytesExt};
+    /// use generic_arrayvec::typenum::U8;
+    /// use generic_arrayvec::GenericArrayVec;
+    ///
+    /// let vec = GenericArrayVec::<u8, U8>::from_base64("aGVsbG8=").unwrap();
+    /// assert_eq!(&vec[..], b"hello");
+    ///
+    /// assert_eq!(
+    ///     GenericArrayVec::<u8, U8>::from_base64("aGVsbG8"),
+    ///     Err(FromBase64Error::InvalidLength),
+    /// );
+    /// assert_eq!(
+    ///     GenericArrayVec::<u8, U8>::from_base64("aGVsbG8sIHdvcmxk"),
+    ///     Err(FromBase64Error::CapacityExceeded),
+    /// );
+    /// ```
+    #[cfg(feature = "base64")]
+    fn from_base64(s: &str) -> Result<GenericArrayVec<u8, N>, FromBase64Error> {
+        let digits = s.as_bytes();
+        if digits.len() % 4 != 0 {
+            return Err(FromBase64Error::InvalidLength);
+        }
+
+        let padding = digits
+            .iter()
+            .rev()
+            .take_while(|&&digit| digit == b'=')
+            .count();
+        if padding > 2 {
+            let index = digits.len() - padding;
+            return Err(FromBase64Error::InvalidByte { index, byte: b'=' });
+        }
+        let len = digits.len() / 4 * 3 - padding;
+        if len > N::USIZE {
+            return Err(FromBase64Error::CapacityExceeded);
+        }
+
+        let mut vec = GenericArrayVec::new();
+        for (chunk_index, chunk) in digits.chunks(4).enumerate() {
+            let mut group = 0;
+            for (i, &digit) in chunk.iter().enumerate() {
+                let index = chunk_index * 4 + i;
+                let value = if index >= digits.len() - padding {
+                    0
+                } else {
+                    base64_value(digit)
+                        .ok_or(FromBase64Error::InvalidByte { index, byte: digit })?
+                };
+                group = group << 6 | value;
+            }
+
+            let bytes = [(group >> 16) as u8, (group >> 8) as u8, group as u8];
+            let take = (len - vec.len()).min(3);
+            // The bits of the last group that don't make up a whole decoded byte must be zero, so
+            // that each byte string has exactly one encoding.
+            if bytes[take..].iter().any(|&byte| byte != 0) {
+                let index = digits.len() - padding - 1;
+                return Err(FromBase64Error::InvalidByte {
+                    index,
+                    byte: digits[index],
+                });
+            }
+            vec.try_extend_from_slice(&bytes[..take])
+                .expect("the decoded length was checked against the capacity");
+        }
+
+        Ok(vec)
+    }
+
+    /// Encodes the bytes as base64, using the standard alphabet with padding, into a string of
+    /// capacity `O`.
+    ///
+    /// No allocation is needed. The encoded length is 4 digits for every 3 bytes, rounded up, so
+    /// `O` should be at least that for the longest contents you expect.
+    ///
+    /// **Errors** if the encoded string doesn't fit within `O`.
+    ///
+    /// Requires the `base64` feature.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::bytes::GenericArrayVecBytesExt;
+    /// use generic_arrayvec::typenum::{U4, U8};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from(*b"hello");
+    ///
+    /// assert_eq!(&vec.to_base64::<U8>().unwrap()[..], "aGVsbG8=");
+    /// assert!(vec.to_base64::<U4>().is_err());
+    /// ```
+    #[cfg(feature = "base64")]
+    fn to_base64<O>(&self) -> Result<GenericArrayString<O>, CapacityError>
+    where
+        O: Capacity<u8>,
+        ArrayvecStorage<u8, O>: Array<Item = u8>,
+        O::ArrayType: Copy,
+    {
+        if self.len().div_ceil(3) * 4 > O::USIZE {
+            return Err(CapacityError::new(()));
+        }
+
+        let mut string = GenericArrayString::new();
+        for chunk in self.chunks(3) {
+            let group = chunk.iter().enumerate().fold(0, |group, (i, &byte)| {
+                group | u32::from(byte) << (16 - 8 * i)
+            });
+            for i in 0..4 {
+                let c = if i <= chunk.len() {
+                    BASE64_ALPHABET[(group >> (18 - 6 * i) & 0x3f) as usize]
+                } else {
+                    b'='
+                };
+                string.push(char::from(c));
+            }
+        }
+
+        Ok(string)
+    }
+
+    /// Returns the CRC-32 checksum of the vector's contents.
+    ///
+    /// This is the common CRC-32 variant used by zlib, gzip and Ethernet, also known as
+    /// CRC-32/ISO-HDLC. Only the `len` bytes in the vector are covered, not the spare capacity.
+    ///
+    /// Requires the `crc` feature.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::bytes::GenericArrayVecBytesExt;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from(*b"123456789");
+    ///
+    /// assert_eq!(vec.crc32(), 0xcbf4_3926);
+    /// ```
+    #[cfg(feature = "crc")]
+    fn crc32(&self) -> u32 {
+        CRC32.checksum(self)
+    }
+
+    /// Returns the 64-bit FNV-1a hash of the vector's contents.
+    ///
+    /// Only the `len` bytes in the vector are covered, not the spare capacity. FNV-1a is fast and
+    /// simple, but not suited to untrusted input, where collisions can be forced.
+    ///
+    /// Requires the `fnv` feature.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::bytes::GenericArrayVecBytesExt;
+    /// use generic_arrayvec::typenum::U8;
+    /// use generic_arrayvec::GenericArrayVec;
+    ///
+    /// let mut vec = GenericArrayVec::<u8, U8>::new();
+    /// assert_eq!(vec.fnv1a(), 0xcbf2_9ce4_8422_2325);
+    ///
+    /// vec.push(b'a');
+    /// assert_eq!(vec.fnv1a(), 0xaf63_dc4c_8601_ec8c);
+    /// ```
+    #[cfg(feature = "fnv")]
+    fn fnv1a(&self) -> u64 {
+        use core::hash::Hasher;
+
+        let mut hasher = fnv::FnvHasher::default();
+        hasher.write(self);
+        hasher.finish()
+    }
+}
+
+// Built at compile time, so the lookup table isn't recomputed on every call.
+#[cfg(feature = "crc")]
+const CRC32: crc::Crc<u32> = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC);
+
+#[cfg(feature = "base64")]
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+#[cfg(feature = "base64")]
+fn base64_value(digit: u8) -> Option<u32> {
+    let value = match digit {
+        b'A'..=b'Z' => digit - b'A',
+        b'a'..=b'z' => digit - b'a' + 26,
+        b'0'..=b'9' => digit - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => return None,
+    };
+
+    Some(u32::from(value))
+}
+
+/// The error returned by [`GenericArrayVecBytesExt::from_base64`].
+///
+/// Requires the `base64` feature.
+#[cfg(feature = "base64")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromBase64Error {
+    /// The input's length isn't a multiple of 4.
+    InvalidLength,
+    /// The byte at `index` isn't a base64 digit, or is padding in the wrong place.
+    InvalidByte { index: usize, byte: u8 },
+    /// The decoded bytes don't fit within the vector's capacity.
+    CapacityExceeded,
+}
+
+#[cfg(feature = "base64")]
+impl fmt::Display for FromBase64Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            FromBase64Error::InvalidLength => f.write_str("base64 length is not a multiple of 4"),
+            FromBase64Error::InvalidByte { index, byte } => {
+                write!(f, "invalid base64 byte {:#04x} at index {}", byte, index)
+            }
+            FromBase64Error::CapacityExceeded => {
+                f.write_str("decoded base64 doesn't fit within the capacity")
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "base64", feature = "std"))]
+impl std::error::Error for FromBase64Error {}
@@ -0,0 +1,119 @@
+use crate::{ArrayvecStorage, Capacity, GenericArrayVec};
+use arrayvec::Array;
+use core::fmt::{self, Debug};
+use core::iter::Chain;
+use core::slice;
+
+/// A read-only view of two [`GenericArrayVec`]s as one sequence, the elements of the first
+/// followed by those of the second.
+///
+/// No elements are copied; the view just borrows both vectors.
+///
+/// ```rust
+/// use generic_arrayvec::typenum::{U2, U4};
+/// use generic_arrayvec::{Chain2, GenericArrayVec, GenericArrayVecExt};
+///
+/// let a = GenericArrayVec::<_, U2>::generic_from([1, 2]);
+/// let b: GenericArrayVec<_, U4> = (3..6).collect();
+/// let chain = Chain2::new(&a, &b);
+///
+/// assert_eq!(chain.len(), 5);
+/// assert_eq!(chain.get(3), Some(&4));
+/// assert!(chain.iter().eq(&[1, 2, 3, 4, 5]));
+/// ```
+pub struct Chain2<'a, T, N, M>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    M: Capacity<T>,
+    ArrayvecStorage<T, M>: Array<Item = T>,
+{
+    first: &'a GenericArrayVec<T, N>,
+    second: &'a GenericArrayVec<T, M>,
+}
+
+impl<'a, T, N, M> Chain2<'a, T, N, M>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    M: Capacity<T>,
+    ArrayvecStorage<T, M>: Array<Item = T>,
+{
+    /// Creates a view of the elements of `first` followed by those of `second`.
+    pub fn new(first: &'a GenericArrayVec<T, N>, second: &'a GenericArrayVec<T, M>) -> Self {
+        Chain2 { first, second }
+    }
+
+    /// Returns the combined number of elements in both vectors.
+    pub fn len(&self) -> usize {
+        self.first.len() + self.second.len()
+    }
+
+    /// Returns `true` if both vectors are empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the element at position `index` in the combined sequence, or
+    /// `None` if it's out of bounds.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        match index.checked_sub(self.first.len()) {
+            None => self.first.get(index),
+            Some(index) => self.second.get(index),
+        }
+    }
+
+    /// Returns an iterator over references to the elements of both vectors, in order.
+    pub fn iter(&self) -> Chain<slice::Iter<'a, T>, slice::Iter<'a, T>> {
+        self.first.iter().chain(self.second.iter())
+    }
+}
+
+impl<'a, T, N, M> Clone for Chain2<'a, T, N, M>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    M: Capacity<T>,
+    ArrayvecStorage<T, M>: Array<Item = T>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, N, M> Copy for Chain2<'a, T, N, M>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    M: Capacity<T>,
+    ArrayvecStorage<T, M>: Array<Item = T>,
+{
+}
+
+impl<'a, T, N, M> Debug for Chain2<'a, T, N, M>
+where
+    T: Debug,
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    M: Capacity<T>,
+    ArrayvecStorage<T, M>: Array<Item = T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T, N, M> IntoIterator for &Chain2<'a, T, N, M>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    M: Capacity<T>,
+    ArrayvecStorage<T, M>: Array<Item = T>,
+{
+    type Item = &'a T;
+    type IntoIter = Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
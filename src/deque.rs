@@ -0,0 +1,421 @@
+use crate::Capacity;
+use arrayvec::CapacityError;
+use core::fmt::{self, Debug};
+use core::iter::FusedIterator;
+use core::mem::MaybeUninit;
+use core::{ptr, slice};
+use generic_array::GenericArray;
+
+/// A fixed-capacity double-ended queue, backed by a [`GenericArray`].
+///
+/// Elements can be pushed and popped at either end in constant time. The storage is used as a
+/// ring buffer, so the elements may wrap around the end of the array; [`as_slices`] returns them
+/// as two slices, whereas [`iter`] hides this and always yields them front to back.
+///
+/// Unlike [`GenericArrayVec`](crate::GenericArrayVec), a deque's length is always stored as a
+/// `usize`, so it only needs the `N: Capacity<T>` bound.
+///
+/// [`as_slices`]: GenericArrayDeque::as_slices
+/// [`iter`]: GenericArrayDeque::iter
+///
+/// ```rust
+/// use generic_arrayvec::deque::GenericArrayDeque;
+/// use generic_arrayvec::typenum::U3;
+///
+/// let mut deque = GenericArrayDeque::<i32, U3>::new();
+/// deque.push_back(2);
+/// deque.push_back(3);
+/// deque.push_front(1);
+/// assert!(deque.try_push_back(4).is_err());
+///
+/// assert_eq!(deque.pop_front(), Some(1));
+/// deque.push_back(4);
+///
+/// assert!(deque.iter().eq(&[2, 3, 4]));
+/// ```
+pub struct GenericArrayDeque<T, N>
+where
+    N: Capacity<T>,
+{
+    buf: MaybeUninit<GenericArray<T, N>>,
+    // The physical index of the front element. Always less than the capacity, unless it's zero.
+    head: usize,
+    len: usize,
+}
+
+impl<T, N> GenericArrayDeque<T, N>
+where
+    N: Capacity<T>,
+{
+    /// Creates a new, empty deque.
+    pub fn new() -> Self {
+        GenericArrayDeque {
+            buf: MaybeUninit::uninit(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the deque.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the deque contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the deque is filled to its capacity.
+    pub fn is_full(&self) -> bool {
+        self.len == N::USIZE
+    }
+
+    /// Returns the capacity of the deque, which is `N`.
+    pub fn capacity(&self) -> usize {
+        N::USIZE
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// **Panics** if the deque is full.
+    pub fn push_back(&mut self, element: T) {
+        self.try_push_back(element).unwrap()
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// **Errors** if the deque is full, returning the element.
+    pub fn try_push_back(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(element));
+        }
+
+        let index = self.physical_index(self.len);
+        // SAFETY: the deque isn't full, so the slot just past the back element is in bounds and
+        // uninitialized.
+        unsafe { self.slot_ptr(index).write(element) };
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Prepends an element to the front of the deque.
+    ///
+    /// **Panics** if the deque is full.
+    pub fn push_front(&mut self, element: T) {
+        self.try_push_front(element).unwrap()
+    }
+
+    /// Prepends an element to the front of the deque.
+    ///
+    /// **Errors** if the deque is full, returning the element.
+    pub fn try_push_front(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(element));
+        }
+
+        // The capacity is nonzero here, since the deque isn't full.
+        self.head = if self.head == 0 {
+            N::USIZE - 1
+        } else {
+            self.head - 1
+        };
+        // SAFETY: the deque isn't full, so the slot just before the front element is in bounds
+        // and uninitialized.
+        unsafe { self.slot_ptr(self.head).write(element) };
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes the back element and returns it, or `None` if the deque is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        let index = self.physical_index(self.len);
+        // SAFETY: the slot held the back element, which is no longer counted by `len`.
+        Some(unsafe { self.slot_ptr(index).read() })
+    }
+
+    /// Removes the front element and returns it, or `None` if the deque is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let index = self.head;
+        self.head = self.physical_index(1);
+        self.len -= 1;
+        // SAFETY: the slot held the front element, which is no longer counted by `head`/`len`.
+        Some(unsafe { self.slot_ptr(index).read() })
+    }
+
+    /// Removes the element at position `index`, counting from the front, and returns it, in
+    /// constant time.
+    ///
+    /// The front element is moved into the removed element's place, so the order of the rest is
+    /// unchanged apart from that. This is the counterpart to
+    /// [`ArrayVec::swap_remove`](arrayvec::ArrayVec::swap_remove), disturbing the front instead of
+    /// the back.
+    ///
+    /// **Panics** if `index` is out of bounds.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::deque::GenericArrayDeque;
+    /// use generic_arrayvec::typenum::U4;
+    ///
+    /// let mut deque = GenericArrayDeque::<i32, U4>::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// deque.push_back(3);
+    /// deque.push_back(4);
+    ///
+    /// assert_eq!(deque.swap_remove_front(2), 3);
+    /// assert!(deque.iter().eq(&[2, 1, 4]));
+    /// ```
+    pub fn swap_remove_front(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len,
+            "swap_remove_front index (is {}) should be < len (is {})",
+            index,
+            self.len
+        );
+
+        let hole = self.physical_index(index);
+        // SAFETY: both slots are in bounds and initialized, since `index < len`. They may be the
+        // same slot, which `ptr::swap` allows.
+        unsafe { ptr::swap(self.slot_ptr(hole), self.slot_ptr(self.head)) };
+        // The deque is nonempty, so this always pops the removed element.
+        self.pop_front().unwrap()
+    }
+
+    /// Returns a reference to the element at position `index`, counting from the front, or `None`
+    /// if it's out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            // SAFETY: the first `len` slots starting from `head` are initialized.
+            Some(unsafe { &*self.slot_ptr_const(self.physical_index(index)) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at position `index`, counting from the front,
+    /// or `None` if it's out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            // SAFETY: the first `len` slots starting from `head` are initialized.
+            Some(unsafe { &mut *self.slot_ptr(self.physical_index(index)) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the front element, or `None` if the deque is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the back element, or `None` if the deque is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    /// Removes all elements from the deque.
+    pub fn clear(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        let (front, back) = (front as *mut [T], back as *mut [T]);
+        self.head = 0;
+        self.len = 0;
+        // SAFETY: the slices covered exactly the initialized elements, which are no longer
+        // counted by `len`, so they're dropped exactly once.
+        unsafe {
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+        }
+    }
+
+    /// Returns the elements as a pair of slices, which together hold them in front-to-back
+    /// order.
+    ///
+    /// The second slice is empty unless the elements wrap around the end of the storage.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front_len, back_len) = self.slice_lens();
+        let base = self.slot_ptr_const(0);
+        // SAFETY: the `front_len` slots starting at `head`, and the `back_len` slots starting at
+        // the beginning of the storage, are exactly the initialized elements.
+        unsafe {
+            (
+                slice::from_raw_parts(base.add(self.head), front_len),
+                slice::from_raw_parts(base, back_len),
+            )
+        }
+    }
+
+    /// Returns the elements as a pair of mutable slices, which together hold them in
+    /// front-to-back order.
+    ///
+    /// The second slice is empty unless the elements wrap around the end of the storage.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front_len, back_len) = self.slice_lens();
+        let base = self.slot_ptr(0);
+        // SAFETY: as in `as_slices`; the two slices don't overlap, since `len` never exceeds the
+        // capacity.
+        unsafe {
+            (
+                slice::from_raw_parts_mut(base.add(self.head), front_len),
+                slice::from_raw_parts_mut(base, back_len),
+            )
+        }
+    }
+
+    /// Returns an iterator over references to the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter {
+            front: front.iter(),
+            back: back.iter(),
+        }
+    }
+
+    // Maps a position counted from the front to an index into the storage.
+    fn physical_index(&self, index: usize) -> usize {
+        // Both `head` and `index` are at most the capacity, so this can't overflow.
+        let index = self.head + index;
+        if index >= N::USIZE {
+            index - N::USIZE
+        } else {
+            index
+        }
+    }
+
+    // Returns the lengths of the slices returned by `as_slices`.
+    fn slice_lens(&self) -> (usize, usize) {
+        let contiguous = N::USIZE - self.head;
+        if self.len <= contiguous {
+            (self.len, 0)
+        } else {
+            (contiguous, self.len - contiguous)
+        }
+    }
+
+    fn slot_ptr_const(&self, index: usize) -> *const T {
+        (self.buf.as_ptr() as *const T).wrapping_add(index)
+    }
+
+    fn slot_ptr(&mut self, index: usize) -> *mut T {
+        (self.buf.as_mut_ptr() as *mut T).wrapping_add(index)
+    }
+}
+
+impl<T, N> Drop for GenericArrayDeque<T, N>
+where
+    N: Capacity<T>,
+{
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, N> Default for GenericArrayDeque<T, N>
+where
+    N: Capacity<T>,
+{
+    fn default() -> Self {
+        GenericArrayDeque::new()
+    }
+}
+
+impl<T, N> Clone for GenericArrayDeque<T, N>
+where
+    T: Clone,
+    N: Capacity<T>,
+{
+    fn clone(&self) -> Self {
+        let mut deque = GenericArrayDeque::new();
+        for element in self {
+            deque.push_back(element.clone());
+        }
+
+        deque
+    }
+}
+
+impl<T, N> Debug for GenericArrayDeque<T, N>
+where
+    T: Debug,
+    N: Capacity<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<'a, T, N> IntoIterator for &'a GenericArrayDeque<T, N>
+where
+    N: Capacity<T>,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over references to the elements of a [`GenericArrayDeque`], front to back.
+///
+/// Returned by [`GenericArrayDeque::iter`].
+pub struct Iter<'a, T> {
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            front: self.front.clone(),
+            back: self.back.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> Debug for Iter<'a, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
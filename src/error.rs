@@ -0,0 +1,52 @@
+use core::fmt;
+
+/// The error returned when an index or position is past the end of a vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfBounds {
+    /// The offending index.
+    pub index: usize,
+    /// The length of the vector at the time.
+    pub len: usize,
+}
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index {} is out of bounds for a vector of length {}",
+            self.index, self.len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfBounds {}
+
+/// The error returned by
+/// [`GenericArrayStringExt::generic_from_utf8_iter`](crate::GenericArrayStringExt::generic_from_utf8_iter).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Utf8OrCapacityError {
+    /// The bytes aren't valid UTF-8. Only the first `valid_up_to` bytes form whole, valid
+    /// characters.
+    InvalidUtf8 { valid_up_to: usize },
+    /// The string doesn't fit within the capacity.
+    Capacity,
+}
+
+impl fmt::Display for Utf8OrCapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Utf8OrCapacityError::InvalidUtf8 { valid_up_to } => write!(
+                f,
+                "invalid UTF-8 sequence after {} valid bytes",
+                valid_up_to
+            ),
+            Utf8OrCapacityError::Capacity => {
+                f.write_str("the string doesn't fit within the capacity")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Utf8OrCapacityError {}
@@ -0,0 +1,192 @@
+use crate::{ArrayvecStorage, Capacity, GenericArrayVec, GenericArrayVecExt};
+use arrayvec::Array;
+use core::convert::TryFrom;
+use core::fmt::{self, Debug};
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+use core::slice::SliceIndex;
+use generic_array::{ArrayLength, GenericArray};
+
+/// A vector that's always filled to its capacity `N`.
+///
+/// Wrap one in a domain type, such as `struct Ipv4Octets(Full<u8, U4>)`, to have the type system
+/// keep track of the invariant, instead of checking it with an `unwrap()` at each
+/// [`into_generic_array`](crate::GenericArrayVecExt::into_generic_array). The only ways to build a
+/// `Full` are from an array, which is full by definition, or by checking a vector with
+/// [`TryFrom`]. There are no methods that change the length.
+///
+/// `Full` dereferences to [`GenericArray<T, N>`], and can be indexed directly like an array.
+///
+/// ```rust
+/// use core::convert::TryFrom;
+/// use generic_arrayvec::typenum::U4;
+/// use generic_arrayvec::{Full, GenericArrayVec};
+///
+/// let partial: GenericArrayVec<u8, U4> = [192, 168].iter().copied().collect();
+/// assert!(Full::try_from(partial).is_err());
+///
+/// let vec: GenericArrayVec<u8, U4> = [192, 168, 0, 1].iter().copied().collect();
+/// let mut octets = Full::try_from(vec).unwrap();
+/// octets[3] = 2;
+///
+/// assert_eq!(&octets[..], &[192, 168, 0, 2]);
+/// assert_eq!(octets.len(), 4);
+/// ```
+pub struct Full<T, N>(GenericArray<T, N>)
+where
+    N: ArrayLength<T>;
+
+impl<T, N> Full<T, N>
+where
+    N: ArrayLength<T>,
+{
+    /// Creates a `Full` from an array or `GenericArray`.
+    pub fn from_array<A>(arr: A) -> Self
+    where
+        A: Into<GenericArray<T, N>>,
+    {
+        Full(arr.into())
+    }
+
+    /// Returns the elements as a `GenericArray`.
+    pub fn into_array(self) -> GenericArray<T, N> {
+        self.0
+    }
+
+    /// Returns the elements as a full vector.
+    pub fn into_vec(self) -> GenericArrayVec<T, N>
+    where
+        N: Capacity<T>,
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        GenericArrayVec::generic_from(self.0)
+    }
+}
+
+impl<T, N> TryFrom<GenericArrayVec<T, N>> for Full<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    type Error = GenericArrayVec<T, N>;
+
+    /// **Errors** if the vector isn't full, returning it unchanged.
+    fn try_from(vec: GenericArrayVec<T, N>) -> Result<Self, Self::Error> {
+        vec.into_generic_array().map(Full)
+    }
+}
+
+impl<T, N> From<GenericArray<T, N>> for Full<T, N>
+where
+    N: ArrayLength<T>,
+{
+    fn from(arr: GenericArray<T, N>) -> Self {
+        Full(arr)
+    }
+}
+
+impl<T, N> From<Full<T, N>> for GenericArray<T, N>
+where
+    N: ArrayLength<T>,
+{
+    fn from(full: Full<T, N>) -> Self {
+        full.0
+    }
+}
+
+impl<T, N> Deref for Full<T, N>
+where
+    N: ArrayLength<T>,
+{
+    type Target = GenericArray<T, N>;
+
+    fn deref(&self) -> &GenericArray<T, N> {
+        &self.0
+    }
+}
+
+impl<T, N> DerefMut for Full<T, N>
+where
+    N: ArrayLength<T>,
+{
+    fn deref_mut(&mut self) -> &mut GenericArray<T, N> {
+        &mut self.0
+    }
+}
+
+impl<T, N, I> Index<I> for Full<T, N>
+where
+    N: ArrayLength<T>,
+    I: SliceIndex<[T]>,
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &I::Output {
+        &self.0.as_slice()[index]
+    }
+}
+
+impl<T, N, I> IndexMut<I> for Full<T, N>
+where
+    N: ArrayLength<T>,
+    I: SliceIndex<[T]>,
+{
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        &mut self.0.as_mut_slice()[index]
+    }
+}
+
+impl<T, N> Clone for Full<T, N>
+where
+    T: Clone,
+    N: ArrayLength<T>,
+{
+    fn clone(&self) -> Self {
+        Full(self.0.clone())
+    }
+}
+
+impl<T, N> Copy for Full<T, N>
+where
+    T: Copy,
+    N: ArrayLength<T>,
+    N::ArrayType: Copy,
+{
+}
+
+impl<T, N> Debug for Full<T, N>
+where
+    T: Debug,
+    N: ArrayLength<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.as_slice().fmt(f)
+    }
+}
+
+impl<T, N> PartialEq for Full<T, N>
+where
+    T: PartialEq,
+    N: ArrayLength<T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_slice() == other.0.as_slice()
+    }
+}
+
+impl<T, N> Eq for Full<T, N>
+where
+    T: Eq,
+    N: ArrayLength<T>,
+{
+}
+
+impl<T, N> Hash for Full<T, N>
+where
+    T: Hash,
+    N: ArrayLength<T>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_slice().hash(state);
+    }
+}
@@ -0,0 +1,281 @@
+use crate::{ArrayvecStorage, Capacity, GenericArrayString};
+#[cfg(feature = "miniserde")]
+use alloc::borrow::Cow;
+use arrayvec::{Array, CapacityError};
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display};
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// A newtype around [`GenericArrayString`], the string counterpart to [`GVec`](crate::GVec).
+///
+/// Being local to this crate, it can implement traits from other crates that
+/// `GenericArrayString`, an alias of arrayvec's [`ArrayString`](arrayvec::ArrayString), can't,
+/// such as miniserde's. It dereferences to `str`, and converts to and from `GenericArrayString`
+/// for free, for anything not covered here.
+///
+/// ```rust
+/// use generic_arrayvec::typenum::U8;
+/// use generic_arrayvec::GString;
+///
+/// let mut string = GString::<U8>::new();
+/// string.try_push_str("hello").unwrap();
+///
+/// assert_eq!(&string[..], "hello");
+/// assert!(string.try_push_str(", world").is_err());
+/// ```
+pub struct GString<N>(GenericArrayString<N>)
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy;
+
+impl<N> GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    /// Creates a new, empty `GString`.
+    pub fn new() -> Self {
+        GString(GenericArrayString::new())
+    }
+
+    /// Returns the length of the string, in bytes.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the capacity of the string, in bytes, which is `N`.
+    pub fn capacity(&self) -> usize {
+        N::USIZE
+    }
+
+    /// Appends a string slice to the end of the string.
+    ///
+    /// **Errors** if it doesn't fit, returning it and leaving the string unchanged.
+    pub fn try_push_str<'a>(&mut self, s: &'a str) -> Result<(), CapacityError<&'a str>> {
+        self.0.try_push_str(s)
+    }
+
+    /// Returns the contents as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the wrapped `GenericArrayString`.
+    pub fn into_inner(self) -> GenericArrayString<N> {
+        self.0
+    }
+}
+
+impl<N> Clone for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N> Copy for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+}
+
+// As with `GVec`, these are written out rather than derived, since deriving would also require
+// the storage type to implement each trait.
+impl<N> PartialEq for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<N> PartialEq<str> for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<N> Eq for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+}
+
+impl<N> Hash for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<N> PartialOrd for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<N> Default for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn default() -> Self {
+        GString::new()
+    }
+}
+
+impl<N> Debug for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<N> Display for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<N> Deref for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<N> AsRef<str> for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<N> From<GenericArrayString<N>> for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn from(string: GenericArrayString<N>) -> Self {
+        GString(string)
+    }
+}
+
+impl<N> From<GString<N>> for GenericArrayString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn from(string: GString<N>) -> Self {
+        string.0
+    }
+}
+
+/// Serializes the contents as a string. Requires the `miniserde` feature.
+#[cfg(feature = "miniserde")]
+impl<N> miniserde::Serialize for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn begin(&self) -> miniserde::ser::Fragment<'_> {
+        miniserde::ser::Fragment::Str(Cow::Borrowed(self.as_str()))
+    }
+}
+
+/// Deserializes a string. Requires the `miniserde` feature.
+///
+/// Deserializing a string longer than the capacity is an error.
+#[cfg(feature = "miniserde")]
+impl<N> miniserde::Deserialize for GString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+    N::ArrayType: Copy,
+{
+    fn begin(out: &mut Option<Self>) -> &mut dyn miniserde::de::Visitor {
+        miniserde::make_place!(Place);
+
+        impl<N> miniserde::de::Visitor for Place<GString<N>>
+        where
+            N: Capacity<u8>,
+            ArrayvecStorage<u8, N>: Array<Item = u8>,
+            N::ArrayType: Copy,
+        {
+            fn string(&mut self, s: &str) -> miniserde::Result<()> {
+                let string = GenericArrayString::from(s).map_err(|_| miniserde::Error)?;
+                self.out = Some(GString(string));
+                Ok(())
+            }
+        }
+
+        Place::new(out)
+    }
+}
@@ -0,0 +1,613 @@
+use crate::private::Sealed;
+use crate::{ArrayvecStorage, Capacity, GenericArrayVec, SameCapacity, TryExtend};
+#[cfg(feature = "miniserde")]
+use alloc::boxed::Box;
+use arrayvec::{Array, ArrayVec, CapacityError, IntoIter};
+use core::cmp::Ordering;
+use core::fmt::{self, Debug};
+use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
+use core::ops::{Deref, DerefMut};
+use core::slice;
+use generic_array::GenericArray;
+
+/// A newtype around [`GenericArrayVec`] with the common vector methods defined directly on it.
+///
+/// `GenericArrayVec` is a type alias of arrayvec's [`ArrayVec`](arrayvec::ArrayVec), so its
+/// methods are documented over there, in terms of the backing storage type. `GVec` exposes the
+/// commonly used ones itself, in terms of `T` and `N`, which is friendlier to docs and IDE
+/// autocompletion. It dereferences to `[T]`, and converts to and from `GenericArrayVec` for free,
+/// for anything not covered here.
+///
+/// Unlike `GenericArrayVec`, generic code only needs a single `N: GVecCapacity<T>` bound to use a
+/// `GVec<T, N>`; see [`GVecCapacity`].
+///
+/// ```rust
+/// use generic_arrayvec::typenum::U3;
+/// use generic_arrayvec::GVec;
+///
+/// let mut vec = GVec::<i32, U3>::new();
+/// vec.push(1);
+/// vec.push(2);
+///
+/// assert_eq!(&vec[..], &[1, 2]);
+/// assert_eq!(vec.remaining_capacity(), 1);
+/// ```
+pub struct GVec<T, N>(ArrayVec<N::Storage>)
+where
+    N: GVecCapacity<T>;
+
+impl<T, N> GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    /// Creates a new, empty `GVec`.
+    pub fn new() -> Self {
+        GVec(ArrayVec::new())
+    }
+
+    /// Creates a full `GVec` from an array or `GenericArray`.
+    pub fn from_array<A>(arr: A) -> Self
+    where
+        A: Into<GenericArray<T, N>>,
+    {
+        GVec(ArrayVec::from(N::storage_from_array(arr.into())))
+    }
+
+    /// Returns the number of elements in the vector.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the capacity of the vector, which is `N`.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Returns `true` if the vector is filled to its capacity.
+    pub fn is_full(&self) -> bool {
+        self.0.is_full()
+    }
+
+    /// Returns how many more elements fit in the vector.
+    pub fn remaining_capacity(&self) -> usize {
+        self.0.remaining_capacity()
+    }
+
+    /// Appends an element to the back of the vector.
+    ///
+    /// **Panics** if the vector is full.
+    pub fn push(&mut self, element: T) {
+        self.0.push(element)
+    }
+
+    /// Appends an element to the back of the vector.
+    ///
+    /// **Errors** if the vector is full, returning the element.
+    pub fn try_push(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        self.0.try_push(element)
+    }
+
+    /// Removes the last element of the vector and returns it, or `None` if it's empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after it to the right.
+    ///
+    /// **Panics** if `index > len` or the vector is full.
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.0.insert(index, element)
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after it to the right.
+    ///
+    /// **Errors** if the vector is full, returning the element.
+    ///
+    /// **Panics** if `index > len`.
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        self.0.try_insert(index, element)
+    }
+
+    /// Removes and returns the element at position `index`, shifting all elements after it to the
+    /// left.
+    ///
+    /// **Panics** if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        self.0.remove(index)
+    }
+
+    /// Removes and returns the element at position `index`, replacing it with the last element.
+    ///
+    /// **Panics** if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        self.0.swap_remove(index)
+    }
+
+    /// Shortens the vector to `len` elements, dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than the vector's current length.
+    pub fn truncate(&mut self, len: usize) {
+        self.0.truncate(len)
+    }
+
+    /// Removes all elements from the vector.
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+
+    /// Retains only the elements for which `f` returns `true`.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.0.retain(f)
+    }
+
+    /// Copies all elements of `other` to the back of the vector.
+    ///
+    /// **Errors** if they don't all fit; nothing is copied in that case.
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), CapacityError>
+    where
+        T: Copy,
+    {
+        self.0.try_extend_from_slice(other)
+    }
+
+    /// Returns a slice containing all elements of the vector.
+    pub fn as_slice(&self) -> &[T] {
+        self.0.as_slice()
+    }
+
+    /// Returns a mutable slice containing all elements of the vector.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.0.as_mut_slice()
+    }
+
+    /// Returns the inner vector.
+    ///
+    /// This is a `GenericArrayVec<T, N>`, though generic code with only the `N: GVecCapacity<T>`
+    /// bound sees it as an `ArrayVec<N::Storage>`.
+    pub fn into_inner(self) -> ArrayVec<N::Storage> {
+        self.0
+    }
+
+    /// Returns the elements as a `GenericArray`, if `self` is full to its capacity.
+    ///
+    /// **Errors** if `self` is not filled to capacity.
+    pub fn into_generic_array(self) -> Result<GenericArray<T, N>, Self> {
+        self.0.into_inner().map(N::storage_into_array).map_err(GVec)
+    }
+}
+
+impl<T, N> Clone for GVec<T, N>
+where
+    T: Clone,
+    N: GVecCapacity<T>,
+{
+    fn clone(&self) -> Self {
+        GVec(self.0.clone())
+    }
+
+    /// Reuses the existing elements; see
+    /// [`generic_clone_from`](crate::GenericArrayVecExt::generic_clone_from).
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0)
+    }
+}
+
+// These are written out rather than derived, since deriving would also require the storage type
+// to implement each trait.
+impl<T, N> PartialEq for GVec<T, N>
+where
+    T: PartialEq,
+    N: GVecCapacity<T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T, N> Eq for GVec<T, N>
+where
+    T: Eq,
+    N: GVecCapacity<T>,
+{
+}
+
+impl<T, N> Hash for GVec<T, N>
+where
+    T: Hash,
+    N: GVecCapacity<T>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T, N> PartialOrd for GVec<T, N>
+where
+    T: PartialOrd,
+    N: GVecCapacity<T>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T, N> Ord for GVec<T, N>
+where
+    T: Ord,
+    N: GVecCapacity<T>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T, N> Default for GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    fn default() -> Self {
+        GVec::new()
+    }
+}
+
+impl<T, N> Debug for GVec<T, N>
+where
+    T: Debug,
+    N: GVecCapacity<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T, N> Deref for GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, N> DerefMut for GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, N> AsRef<[T]> for GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, N> AsMut<[T]> for GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, N> From<GenericArrayVec<T, N>> for GVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    fn from(vec: GenericArrayVec<T, N>) -> Self {
+        GVec(vec)
+    }
+}
+
+impl<T, N> From<GVec<T, N>> for GenericArrayVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    fn from(vec: GVec<T, N>) -> Self {
+        vec.0
+    }
+}
+
+/// Compares the elements with those of an array of the same length, so only a full vector can be
+/// equal to one.
+///
+/// Fails to compile if `M` isn't `N`. `GenericArrayVec` itself can't implement this, since both it
+/// and arrays are foreign types; compare against a slice of the array instead, as in
+/// `vec == array[..]`.
+///
+/// ```rust
+/// use generic_arrayvec::typenum::U3;
+/// use generic_arrayvec::GVec;
+///
+/// let mut vec = GVec::<i32, U3>::new();
+/// vec.push(1);
+/// vec.push(2);
+/// assert_ne!(vec, [1, 2, 3]);
+///
+/// vec.push(3);
+/// assert_eq!(vec, [1, 2, 3]);
+/// assert_eq!(vec, &[1, 2, 3]);
+/// ```
+impl<T, N, const M: usize> PartialEq<[T; M]> for GVec<T, N>
+where
+    T: PartialEq,
+    N: GVecCapacity<T>,
+{
+    fn eq(&self, other: &[T; M]) -> bool {
+        SameCapacity::<N, M>::check();
+
+        self.as_slice() == other
+    }
+}
+
+impl<T, N, const M: usize> PartialEq<&[T; M]> for GVec<T, N>
+where
+    T: PartialEq,
+    N: GVecCapacity<T>,
+{
+    fn eq(&self, other: &&[T; M]) -> bool {
+        *self == **other
+    }
+}
+
+/// Requires the `subtle` feature.
+///
+/// `GenericArrayVec` itself can't implement `ConstantTimeEq`, since it's an alias of a type from
+/// another crate; use `GVec` for that, or the byte vector helper
+/// [`ct_eq`](crate::bytes::GenericArrayVecBytesExt::ct_eq).
+#[cfg(feature = "subtle")]
+impl<T, N> subtle::ConstantTimeEq for GVec<T, N>
+where
+    T: subtle::ConstantTimeEq,
+    N: GVecCapacity<T>,
+{
+    /// Compares the elements in constant time, returning false without looking at them if the
+    /// lengths differ.
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.as_slice().ct_eq(other.as_slice())
+    }
+}
+
+/// Serializes the elements as a sequence. Requires the `miniserde` feature.
+///
+/// `GenericArrayVec` itself can't implement miniserde's traits, since it's an alias of a type
+/// from another crate; convert it to a `GVec` first.
+#[cfg(feature = "miniserde")]
+impl<T, N> miniserde::Serialize for GVec<T, N>
+where
+    T: miniserde::Serialize,
+    N: GVecCapacity<T>,
+{
+    fn begin(&self) -> miniserde::ser::Fragment<'_> {
+        struct Elements<'a, T>(slice::Iter<'a, T>);
+
+        impl<'a, T> miniserde::ser::Seq for Elements<'a, T>
+        where
+            T: miniserde::Serialize,
+        {
+            fn next(&mut self) -> Option<&dyn miniserde::Serialize> {
+                let element = self.0.next()?;
+                Some(element)
+            }
+        }
+
+        miniserde::ser::Fragment::Seq(Box::new(Elements(self.iter())))
+    }
+}
+
+/// Deserializes a sequence of elements. Requires the `miniserde` feature.
+///
+/// Deserializing more elements than fit is an error.
+#[cfg(feature = "miniserde")]
+impl<T, N> miniserde::Deserialize for GVec<T, N>
+where
+    T: miniserde::Deserialize,
+    N: GVecCapacity<T>,
+{
+    fn begin(out: &mut Option<Self>) -> &mut dyn miniserde::de::Visitor {
+        miniserde::make_place!(Place);
+
+        impl<T, N> miniserde::de::Visitor for Place<GVec<T, N>>
+        where
+            T: miniserde::Deserialize,
+            N: GVecCapacity<T>,
+        {
+            fn seq(&mut self) -> miniserde::Result<Box<dyn miniserde::de::Seq + '_>> {
+                Ok(Box::new(Builder {
+                    out: &mut self.out,
+                    vec: GVec::new(),
+                    element: None,
+                }))
+            }
+        }
+
+        struct Builder<'a, T, N>
+        where
+            N: GVecCapacity<T>,
+        {
+            out: &'a mut Option<GVec<T, N>>,
+            vec: GVec<T, N>,
+            element: Option<T>,
+        }
+
+        impl<'a, T, N> Builder<'a, T, N>
+        where
+            N: GVecCapacity<T>,
+        {
+            fn shift(&mut self) -> miniserde::Result<()> {
+                match self.element.take() {
+                    Some(element) => self.vec.try_push(element).map_err(|_| miniserde::Error),
+                    None => Ok(()),
+                }
+            }
+        }
+
+        impl<'a, T, N> miniserde::de::Seq for Builder<'a, T, N>
+        where
+            T: miniserde::Deserialize,
+            N: GVecCapacity<T>,
+        {
+            fn element(&mut self) -> miniserde::Result<&mut dyn miniserde::de::Visitor> {
+                self.shift()?;
+                Ok(miniserde::Deserialize::begin(&mut self.element))
+            }
+
+            fn finish(&mut self) -> miniserde::Result<()> {
+                self.shift()?;
+                *self.out = Some(core::mem::take(&mut self.vec));
+                Ok(())
+            }
+        }
+
+        Place::new(out)
+    }
+}
+
+impl<T, N> Extend<T> for GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    /// Stops at capacity; any remaining elements are not taken. Use
+    /// [`try_extend`](TryExtend::try_extend) to find out whether everything fit.
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.0.extend(iter)
+    }
+}
+
+impl<T, N> FromIterator<T> for GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    /// Stops at capacity; any remaining elements are not taken.
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        GVec(iter.into_iter().collect())
+    }
+}
+
+impl<T, N> IntoIterator for GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    type Item = T;
+    type IntoIter = IntoIter<N::Storage>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, N> IntoIterator for &'a GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, N> IntoIterator for &'a mut GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, N> TryExtend<T> for GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    /// Appends the elements of `iter` to the vector, in order.
+    ///
+    /// **Errors** at the first element that doesn't fit, returning that element. The elements
+    /// pushed before it stay in the vector, and no more are taken from `iter`; pass
+    /// `iter.by_ref()` to get at the ones left over.
+    fn try_extend<I>(&mut self, iter: I) -> Result<(), CapacityError<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.0.try_extend(iter)
+    }
+}
+
+/// The bound on the capacity `N` of a [`GVec<T, N>`].
+///
+/// Naming a `GenericArrayVec<T, N>` in generic code takes two bounds, `N: Capacity<T>` and
+/// `ArrayvecStorage<T, N>: Array<Item = T>`, since Rust doesn't carry the second one along with
+/// the first. This trait folds them into one: it's implemented for every `N` satisfying both,
+/// with the storage type as an associated type, so `N: GVecCapacity<T>` is all a `GVec` needs.
+///
+/// ```rust
+/// use generic_arrayvec::{GVec, GVecCapacity};
+///
+/// fn sum<N>(vec: &GVec<u32, N>) -> u32
+/// where
+///     N: GVecCapacity<u32>,
+/// {
+///     vec.iter().sum()
+/// }
+///
+/// assert_eq!(sum(&GVec::from_array([1, 2, 3])), 6);
+/// ```
+pub trait GVecCapacity<T>: Capacity<T> {
+    /// The vector's backing storage, which is always [`ArrayvecStorage<T, Self>`].
+    type Storage: Array<Item = T>;
+
+    #[doc(hidden)]
+    fn storage_from_array(arr: GenericArray<T, Self>) -> Self::Storage;
+
+    #[doc(hidden)]
+    fn storage_into_array(storage: Self::Storage) -> GenericArray<T, Self>;
+
+    fn _sealed(_: Sealed);
+}
+
+impl<T, N> GVecCapacity<T> for N
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    type Storage = ArrayvecStorage<T, N>;
+
+    fn storage_from_array(arr: GenericArray<T, N>) -> Self::Storage {
+        ArrayvecStorage::from(arr)
+    }
+
+    fn storage_into_array(storage: Self::Storage) -> GenericArray<T, N> {
+        storage.into_inner()
+    }
+
+    fn _sealed(_: Sealed) {}
+}
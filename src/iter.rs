@@ -0,0 +1,340 @@
+use crate::{ArrayvecStorage, Capacity, GenericArrayVec};
+use arrayvec::{Array, IntoIter};
+use core::fmt::{self, Debug};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ptr;
+use core::str::CharIndices;
+
+/// An iterator that removes and yields the elements of a [`GenericArrayVec`] matching a
+/// predicate.
+///
+/// Returned by [`GenericArrayVecExt::generic_extract_if`](crate::GenericArrayVecExt::generic_extract_if).
+pub struct ExtractIf<'a, T, N, F>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    F: FnMut(&mut T) -> bool,
+{
+    vec: &'a mut GenericArrayVec<T, N>,
+    // The index of the next element to pass to `pred`.
+    idx: usize,
+    // The number of elements removed so far.
+    del: usize,
+    old_len: usize,
+    pred: F,
+}
+
+impl<'a, T, N, F> ExtractIf<'a, T, N, F>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    F: FnMut(&mut T) -> bool,
+{
+    pub(crate) fn new(vec: &'a mut GenericArrayVec<T, N>, pred: F) -> Self {
+        let old_len = vec.len();
+        // SAFETY: the length is restored in `drop`. Until then, if the iterator is leaked, the
+        // elements are leaked too, rather than the vector being left with holes in it.
+        unsafe { vec.set_len(0) };
+
+        ExtractIf {
+            vec,
+            idx: 0,
+            del: 0,
+            old_len,
+            pred,
+        }
+    }
+}
+
+impl<'a, T, N, F> Iterator for ExtractIf<'a, T, N, F>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let base = self.vec.as_mut_ptr();
+        while self.idx < self.old_len {
+            // SAFETY: the elements from `idx` up to `old_len` are initialized and haven't been
+            // moved yet, so `cur` points to a valid element.
+            unsafe {
+                let cur = base.add(self.idx);
+                let extract = (self.pred)(&mut *cur);
+                // Only advance once `pred` has returned, so that if it panics, the element it was
+                // given is kept.
+                self.idx += 1;
+
+                if extract {
+                    self.del += 1;
+                    return Some(ptr::read(cur));
+                } else if self.del > 0 {
+                    // The `del` slots before `cur` have been vacated, so move it into the first.
+                    ptr::copy_nonoverlapping(cur, cur.sub(self.del), 1);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.old_len - self.idx))
+    }
+}
+
+impl<'a, T, N, F> Drop for ExtractIf<'a, T, N, F>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        // SAFETY: the unvisited elements, from `idx` up to `old_len`, are moved down over the
+        // `del` vacated slots, after which the first `old_len - del` slots are exactly the kept
+        // elements.
+        unsafe {
+            if self.del > 0 && self.idx < self.old_len {
+                let base = self.vec.as_mut_ptr();
+                let src = base.add(self.idx);
+                ptr::copy(src, src.sub(self.del), self.old_len - self.idx);
+            }
+            self.vec.set_len(self.old_len - self.del);
+        }
+    }
+}
+
+impl<'a, T, N, F> Debug for ExtractIf<'a, T, N, F>
+where
+    T: Debug,
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    F: FnMut(&mut T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtractIf")
+            .field("remaining", &self.old_len.saturating_sub(self.idx))
+            .finish_non_exhaustive()
+    }
+}
+
+/// An iterator that moves the elements of a [`GenericArrayVec`] out in batches of `L`.
+///
+/// Returned by [`GenericArrayVecExt::into_generic_chunks`](crate::GenericArrayVecExt::into_generic_chunks).
+pub struct IntoGenericChunks<T, N, L>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    L: Capacity<T>,
+    ArrayvecStorage<T, L>: Array<Item = T>,
+{
+    iter: IntoIter<ArrayvecStorage<T, N>>,
+    _chunk: PhantomData<L>,
+}
+
+impl<T, N, L> IntoGenericChunks<T, N, L>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    L: Capacity<T>,
+    ArrayvecStorage<T, L>: Array<Item = T>,
+{
+    pub(crate) fn new(vec: GenericArrayVec<T, N>) -> Self {
+        assert!(L::USIZE != 0, "chunk size must be nonzero");
+
+        IntoGenericChunks {
+            iter: vec.into_iter(),
+            _chunk: PhantomData,
+        }
+    }
+}
+
+impl<T, N, L> Iterator for IntoGenericChunks<T, N, L>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    L: Capacity<T>,
+    ArrayvecStorage<T, L>: Array<Item = T>,
+{
+    type Item = GenericArrayVec<T, L>;
+
+    fn next(&mut self) -> Option<GenericArrayVec<T, L>> {
+        if self.iter.len() == 0 {
+            return None;
+        }
+
+        Some(self.iter.by_ref().take(L::USIZE).collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, N, L> ExactSizeIterator for IntoGenericChunks<T, N, L>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    L: Capacity<T>,
+    ArrayvecStorage<T, L>: Array<Item = T>,
+{
+    fn len(&self) -> usize {
+        self.iter.len().div_ceil(L::USIZE)
+    }
+}
+
+impl<T, N, L> FusedIterator for IntoGenericChunks<T, N, L>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    L: Capacity<T>,
+    ArrayvecStorage<T, L>: Array<Item = T>,
+{
+}
+
+impl<T, N, L> Debug for IntoGenericChunks<T, N, L>
+where
+    T: Debug,
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    L: Capacity<T>,
+    ArrayvecStorage<T, L>: Array<Item = T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntoGenericChunks")
+            .field("remaining", &self.iter)
+            .field("chunk_size", &L::USIZE)
+            .finish()
+    }
+}
+
+/// An iterator adapter that collects the elements of another iterator into batches of `N`.
+///
+/// Returned by [`IterFixedExt::fixed_chunks`](crate::IterFixedExt::fixed_chunks).
+pub struct FixedChunks<I, N>
+where
+    I: Iterator,
+    N: Capacity<I::Item>,
+    ArrayvecStorage<I::Item, N>: Array<Item = I::Item>,
+{
+    iter: I,
+    done: bool,
+    _chunk: PhantomData<N>,
+}
+
+impl<I, N> FixedChunks<I, N>
+where
+    I: Iterator,
+    N: Capacity<I::Item>,
+    ArrayvecStorage<I::Item, N>: Array<Item = I::Item>,
+{
+    pub(crate) fn new(iter: I) -> Self {
+        assert!(N::USIZE != 0, "chunk size must be nonzero");
+
+        FixedChunks {
+            iter,
+            done: false,
+            _chunk: PhantomData,
+        }
+    }
+}
+
+impl<I, N> Iterator for FixedChunks<I, N>
+where
+    I: Iterator,
+    N: Capacity<I::Item>,
+    ArrayvecStorage<I::Item, N>: Array<Item = I::Item>,
+{
+    type Item = GenericArrayVec<I::Item, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let chunk: GenericArrayVec<_, N> = self.iter.by_ref().take(N::USIZE).collect();
+        // A short chunk means the source ran out, so don't poll it again, in case it isn't fused.
+        if !chunk.is_full() {
+            self.done = true;
+        }
+
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.div_ceil(N::USIZE),
+            upper.map(|upper| upper.div_ceil(N::USIZE)),
+        )
+    }
+}
+
+impl<I, N> FusedIterator for FixedChunks<I, N>
+where
+    I: Iterator,
+    N: Capacity<I::Item>,
+    ArrayvecStorage<I::Item, N>: Array<Item = I::Item>,
+{
+}
+
+impl<I, N> Debug for FixedChunks<I, N>
+where
+    I: Iterator + Debug,
+    N: Capacity<I::Item>,
+    ArrayvecStorage<I::Item, N>: Array<Item = I::Item>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FixedChunks")
+            .field("iter", &self.iter)
+            .field("chunk_size", &N::USIZE)
+            .finish()
+    }
+}
+
+/// An iterator over the byte offsets at which the chars of a string start.
+///
+/// Returned by [`GenericArrayStringExt::char_boundaries`](crate::GenericArrayStringExt::char_boundaries).
+#[derive(Debug, Clone)]
+pub struct CharBoundaries<'a> {
+    chars: CharIndices<'a>,
+}
+
+impl<'a> CharBoundaries<'a> {
+    pub(crate) fn new(string: &'a str) -> Self {
+        CharBoundaries {
+            chars: string.char_indices(),
+        }
+    }
+}
+
+impl<'a> Iterator for CharBoundaries<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        self.chars.next().map(|(offset, _)| offset)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for CharBoundaries<'a> {
+    fn next_back(&mut self) -> Option<usize> {
+        self.chars.next_back().map(|(offset, _)| offset)
+    }
+}
+
+impl<'a> FusedIterator for CharBoundaries<'a> {}
@@ -0,0 +1,87 @@
+use crate::{ArrayvecStorage, Capacity, GenericArrayVec};
+use arrayvec::Array;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+
+/// A wrapper whose [`Hash`] and [`PartialEq`] take the vector's capacity into account, as well as
+/// its contents.
+///
+/// A plain `GenericArrayVec` hashes and compares like a slice, so vectors of different
+/// capacities holding the same elements are interchangeable as keys. That's usually what you
+/// want. Wrap them in `CapacityKeyed` when the capacity matters too, such as when keying a cache
+/// by buffer type: then equal contents in a `U4` and a `U8` vector hash differently and compare
+/// unequal.
+///
+/// ```rust
+/// use generic_arrayvec::typenum::{U4, U8};
+/// use generic_arrayvec::{CapacityKeyed, GenericArrayVec};
+///
+/// let small: GenericArrayVec<i32, U4> = (1..=3).collect();
+/// let big: GenericArrayVec<i32, U8> = (1..=3).collect();
+/// assert_eq!(small, big[..]);
+///
+/// assert_ne!(CapacityKeyed(small), CapacityKeyed(big));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CapacityKeyed<V>(pub V);
+
+impl<V> CapacityKeyed<V> {
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> V {
+        self.0
+    }
+}
+
+impl<T, N> Hash for CapacityKeyed<GenericArrayVec<T, N>>
+where
+    T: Hash,
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        N::USIZE.hash(state);
+        self.0.as_slice().hash(state);
+    }
+}
+
+impl<T, N, M> PartialEq<CapacityKeyed<GenericArrayVec<T, M>>>
+    for CapacityKeyed<GenericArrayVec<T, N>>
+where
+    T: PartialEq,
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    M: Capacity<T>,
+    ArrayvecStorage<T, M>: Array<Item = T>,
+{
+    fn eq(&self, other: &CapacityKeyed<GenericArrayVec<T, M>>) -> bool {
+        N::USIZE == M::USIZE && self.0.as_slice() == other.0.as_slice()
+    }
+}
+
+impl<T, N> Eq for CapacityKeyed<GenericArrayVec<T, N>>
+where
+    T: Eq,
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+}
+
+impl<V> Deref for CapacityKeyed<V> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        &self.0
+    }
+}
+
+impl<V> DerefMut for CapacityKeyed<V> {
+    fn deref_mut(&mut self) -> &mut V {
+        &mut self.0
+    }
+}
+
+impl<V> From<V> for CapacityKeyed<V> {
+    fn from(value: V) -> Self {
+        CapacityKeyed(value)
+    }
+}
@@ -13,6 +13,10 @@
 //! [`GenericArrayVecExt`] and [`GenericArrayStringExt`] that provide additional constructors and
 //! conversions.
 //!
+//! Since the methods of [`GenericArrayVec`] are documented on [`ArrayVec`] in terms of its storage
+//! type, there's also the [`GVec`] newtype, which defines the common vector methods itself. It
+//! also needs only a single [`GVecCapacity`] bound in generic code.
+//!
 //! ## Example
 //!
 //! ```rust
@@ -95,6 +99,43 @@
 //! {
 //! }
 //! ```
+//!
+//! ### The `uniform-index` feature
+//!
+//! The `ArrayvecStorage` bound is needed because a vector's length is stored using the smallest
+//! integer type that fits its capacity, and that type is computed from `N`. If you'd rather have
+//! simpler bounds than smaller vectors, enable the `uniform-index` feature. This makes every
+//! vector store its length as a `usize`, as arrayvec itself does, and lets you drop the
+//! `ArrayvecStorage` bound entirely:
+//!
+//! ```rust
+//! # #[cfg(feature = "uniform-index")]
+//! # mod with_feature {
+//! use generic_arrayvec::{Capacity, GenericArrayVec};
+//!
+//! fn f<T, N>(_arr: GenericArrayVec<T, N>)
+//! where
+//!     N: Capacity<T>,
+//! {
+//! }
+//! # }
+//! ```
+//!
+//! Keep in mind that features are shared by every crate in a build, so enabling this in a library
+//! changes the layout of these types for all of its dependents too.
+//!
+//! ## Serialization
+//!
+//! The `serde` feature enables arrayvec's own `Serialize` and `Deserialize` impls, which also
+//! cover `GenericArrayVec` and `GenericArrayString`. Deserializing more elements than fit is an
+//! error.
+//!
+//! Support for other serialization frameworks can't be added the same way. Both their traits and
+//! the aliased `ArrayVec` and `ArrayString` types are foreign to this crate, so the orphan rule
+//! rules out implementing one for the other here. [`GVec`] and [`GString`] are local, though, so
+//! the `miniserde` feature implements miniserde's `Serialize` and `Deserialize` for them; convert
+//! a vector or string to one of these to use them. As with serde, deserializing more than fits is
+//! an error. For other frameworks, wrap the vector in a newtype of your own.
 
 #![no_std]
 #![warn(
@@ -105,17 +146,74 @@
     unused_qualifications
 )]
 
+#[cfg(any(feature = "alloc", feature = "miniserde"))]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 pub use arrayvec;
 pub use generic_array::{self, typenum};
 
-use arrayvec::{Array, ArrayString, ArrayVec, CapacityError};
+use arrayvec::{Array, ArrayString, ArrayVec, CapacityError, Drain, IntoIter};
+use core::array;
+use core::fmt;
+use core::iter::{Rev, Sum};
+use core::marker::PhantomData;
+use core::mem::{self, size_of, ManuallyDrop, MaybeUninit};
+use core::ops::{Add, Sub};
+use core::ptr;
+use core::slice;
 use core::str::Utf8Error;
-use generic_array::typenum::{IsLess, U1, U2, U256, U4294967296, U65536};
+use generic_array::functional::FunctionalSequence;
+use generic_array::sequence::{Concat, Split};
+use generic_array::typenum::{Diff, Unsigned, U128, U16, U256, U32, U4, U64, U8};
+#[cfg(not(feature = "uniform-index"))]
+use generic_array::typenum::{IsLess, U1, U2, U4294967296, U65536};
 use generic_array::{ArrayLength, GenericArray};
-use plumbing::{ArrayvecStorageRaw, IndexForCapacity, PickIndexBreakpointsForCapacity};
+use iter::{CharBoundaries, ExtractIf, IntoGenericChunks};
+use plumbing::ArrayvecStorageRaw;
+#[cfg(not(feature = "uniform-index"))]
+use plumbing::{IndexForCapacity, PickIndexBreakpointsForCapacity};
+
+pub use builder::{ExactLenBuilder, LenMismatch};
+pub use chain::Chain2;
+pub use error::{OutOfBounds, Utf8OrCapacityError};
+pub use full::Full;
+pub use gstring::GString;
+pub use gvec::{GVec, GVecCapacity};
+pub use keyed::CapacityKeyed;
+pub use seq::{CollectLossy, DynFixedVec, FixedSeq, FixedSeqMut, IterFixedExt};
+#[cfg(feature = "alloc")]
+pub use spill::SpillVec;
 
+/// A fixed-capacity bit set.
+pub mod bitset;
+/// Helpers for byte vectors, i.e. [`GenericArrayVec<u8, N>`].
+pub mod bytes;
+/// A fixed-capacity double-ended queue.
+pub mod deque;
+/// Iterators returned by methods in this crate.
+pub mod iter;
 /// Low-level implementation details you shouldn't need to touch.
 pub mod plumbing;
+/// A fixed-capacity slab, whose elements keep their indices as others are removed.
+pub mod slab;
+/// Helpers for testing code built on this crate.
+///
+/// Requires the `test-util` feature.
+#[cfg(feature = "test-util")]
+pub mod test_util;
+
+mod builder;
+mod chain;
+mod error;
+mod full;
+mod gstring;
+mod gvec;
+mod keyed;
+mod seq;
+#[cfg(feature = "alloc")]
+mod spill;
 
 /// A [`GenericArray`]-backed [`ArrayVec`].
 pub type GenericArrayVec<T, N> = ArrayVec<ArrayvecStorage<T, N>>;
@@ -123,15 +221,89 @@ pub type GenericArrayVec<T, N> = ArrayVec<ArrayvecStorage<T, N>>;
 /// A [`GenericArray`]-backed [`ArrayString`].
 pub type GenericArrayString<N> = ArrayString<ArrayvecStorage<u8, N>>;
 
+/// A [`GenericArrayVec`] whose capacity is given as a const generic `usize`, rather than a
+/// `typenum` type.
+///
+/// This is the same type as `GenericArrayVec<T, U<N>>`, where [`U<N>`](typenum::U) is the
+/// `typenum` integer for `N`, so there's no conversion cost, and [`GenericArrayVecExt`] applies
+/// as usual. `typenum` maps every `N` up to 1024, plus larger powers of two and of ten, among
+/// others; other capacities fail to compile.
+///
+/// Requires the `const-generics` feature, and Rust 1.51 or later.
+///
+/// ```rust
+/// use generic_arrayvec::{ConstArrayVec, GenericArrayVecExt};
+///
+/// let vec = ConstArrayVec::<i32, 4>::generic_from([1, 2, 3, 4]);
+///
+/// assert_eq!(vec.capacity(), 4);
+/// ```
+#[cfg(feature = "const-generics")]
+pub type ConstArrayVec<T, const N: usize> = GenericArrayVec<T, typenum::U<N>>;
+
+/// A [`GenericArrayString`] whose capacity is given as a const generic `usize`, rather than a
+/// `typenum` type.
+///
+/// This is the same type as `GenericArrayString<U<N>>`; see [`ConstArrayVec`] for the details.
+///
+/// Requires the `const-generics` feature, and Rust 1.51 or later.
+///
+/// ```rust
+/// use generic_arrayvec::{ConstArrayString, GenericArrayStringExt};
+///
+/// let string = ConstArrayString::<16>::generic_from("hello").unwrap();
+///
+/// assert_eq!(&string[..], "hello");
+/// assert_eq!(string.capacity(), 16);
+/// ```
+#[cfg(feature = "const-generics")]
+pub type ConstArrayString<const N: usize> = GenericArrayString<typenum::U<N>>;
+
+macro_rules! capacity_aliases {
+    ($($vec:ident, $string:ident: $n:ident;)*) => {$(
+        #[doc = concat!("A [`GenericArrayVec`] with capacity ", stringify!($n), ".")]
+        pub type $vec<T> = GenericArrayVec<T, $n>;
+
+        #[doc = concat!("A [`GenericArrayString`] with capacity ", stringify!($n), ".")]
+        pub type $string = GenericArrayString<$n>;
+    )*};
+}
+
+// Shorthands for common capacities, for code that doesn't need to be generic over them.
+capacity_aliases! {
+    GenericArrayVec4, GenericArrayString4: U4;
+    GenericArrayVec8, GenericArrayString8: U8;
+    GenericArrayVec16, GenericArrayString16: U16;
+    GenericArrayVec32, GenericArrayString32: U32;
+    GenericArrayVec64, GenericArrayString64: U64;
+    GenericArrayVec128, GenericArrayString128: U128;
+    GenericArrayVec256, GenericArrayString256: U256;
+}
+
 /// A wrapper around a [`GenericArray`] that implements the [`Array`] trait from the arrayvec
 /// crate, allowing it to be used as the backing store for [`ArrayVec`] and [`ArrayString`].
 ///
 /// You likely won't need to interact with this type directly, except in `where` clauses when
 /// working with [`GenericArrayVec`] and [`GenericArrayString`]; see their docs for details.
+///
+/// The vector's length is stored using the smallest integer type that fits the capacity, unless
+/// the `uniform-index` feature is enabled, in which case it's always a `usize`.
+#[cfg(not(feature = "uniform-index"))]
 pub type ArrayvecStorage<T, N> = ArrayvecStorageRaw<T, N, IndexForCapacity<N>>;
 
+/// A wrapper around a [`GenericArray`] that implements the [`Array`] trait from the arrayvec
+/// crate, allowing it to be used as the backing store for [`ArrayVec`] and [`ArrayString`].
+///
+/// You likely won't need to interact with this type directly, except in `where` clauses when
+/// working with [`GenericArrayVec`] and [`GenericArrayString`]; see their docs for details.
+///
+/// The `uniform-index` feature is enabled, so the vector's length is always stored as a `usize`.
+#[cfg(feature = "uniform-index")]
+pub type ArrayvecStorage<T, N> = ArrayvecStorageRaw<T, N, usize>;
+
 /// A trait implemented by `typenum`'s unsigned integers, which lets them be used to define the
 /// capacity of [`GenericArrayVec`]/[`GenericArrayString`].
+#[cfg(not(feature = "uniform-index"))]
 pub trait Capacity<T>:
     ArrayLength<T>
     + PickIndexBreakpointsForCapacity
@@ -143,6 +315,7 @@ pub trait Capacity<T>:
 {
 }
 
+#[cfg(not(feature = "uniform-index"))]
 impl<N, T> Capacity<T> for N where
     N: ArrayLength<T>
         + PickIndexBreakpointsForCapacity
@@ -154,6 +327,187 @@ impl<N, T> Capacity<T> for N where
 {
 }
 
+/// A trait implemented by `typenum`'s unsigned integers, which lets them be used to define the
+/// capacity of [`GenericArrayVec`]/[`GenericArrayString`].
+///
+/// The `uniform-index` feature is enabled, so this is just an alias for [`ArrayLength<T>`].
+#[cfg(feature = "uniform-index")]
+pub trait Capacity<T>: ArrayLength<T> {}
+
+#[cfg(feature = "uniform-index")]
+impl<N, T> Capacity<T> for N where N: ArrayLength<T> {}
+
+/// Returns the capacity `N` as a `usize`, in a form usable in `const` contexts.
+///
+/// This works anywhere `N` is a concrete type, including array lengths and const generic
+/// arguments. It can't be used inside a function that's generic over `N` though, since stable Rust
+/// doesn't allow generic parameters in const expressions:
+///
+/// ```rust
+/// use generic_arrayvec::capacity;
+/// use generic_arrayvec::typenum::U8;
+///
+/// let buf = [0u8; capacity::<U8>()];
+///
+/// assert_eq!(buf.len(), 8);
+/// ```
+///
+/// ```rust,compile_fail
+/// use generic_arrayvec::capacity;
+/// use generic_arrayvec::typenum::Unsigned;
+///
+/// fn f<N: Unsigned>() {
+///     let buf = [0u8; capacity::<N>()];
+/// }
+/// ```
+pub const fn capacity<N>() -> usize
+where
+    N: Unsigned,
+{
+    N::USIZE
+}
+
+/// Asserts that `n` equals the capacity `N`.
+///
+/// This is meant to be evaluated in a `const` context, where a mismatch is a compile error rather
+/// than a runtime panic. Use it to check that a size computed for const-generic code agrees with
+/// the typenum capacity used for some storage.
+///
+/// ```rust
+/// use generic_arrayvec::assert_capacity_eq;
+/// use generic_arrayvec::typenum::U16;
+///
+/// const HEADER_LEN: usize = 4 * 4;
+/// const _: () = assert_capacity_eq::<U16>(HEADER_LEN);
+/// ```
+///
+/// ```rust,compile_fail
+/// use generic_arrayvec::assert_capacity_eq;
+/// use generic_arrayvec::typenum::U16;
+///
+/// const _: () = assert_capacity_eq::<U16>(15);
+/// ```
+///
+/// **Panics** if `n != N::USIZE`, when called at runtime.
+pub const fn assert_capacity_eq<N>(n: usize)
+where
+    N: Unsigned,
+{
+    assert!(n == N::USIZE, "capacity doesn't match");
+}
+
+/// Creates a new, empty `GenericArrayVec` in a `const` context, such as the initializer of a
+/// `static`.
+///
+/// This is the same as [`ArrayVec::new`], which can't be called in `const` contexts itself:
+/// arrayvec 0.5 only makes it a `const fn` behind its `unstable-const-fn` feature, which relies on
+/// a nightly feature gate that has since been removed. Instead, the vector is built from zeroed
+/// memory, which is a valid empty vector for every element type, since the storage is
+/// `MaybeUninit` and each of the possible length types reads zero as `0`.
+///
+/// ```rust
+/// use generic_arrayvec::typenum::U16;
+/// use generic_arrayvec::{new_const, GenericArrayVec};
+///
+/// static EMPTY: GenericArrayVec<u8, U16> = new_const();
+///
+/// assert!(EMPTY.is_empty());
+/// assert_eq!(EMPTY.capacity(), 16);
+/// ```
+pub const fn new_const<T, N>() -> GenericArrayVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    // SAFETY: `ArrayVec` holds its storage in a `MaybeUninit`, which is valid for any bytes, and
+    // its length as the storage's `Array::Index`. That's one of `()`, `bool` or an unsigned
+    // integer, restricted by arrayvec's sealed `Index` trait, all of which read zeroed memory as
+    // zero. So this is an empty vector, just as `ArrayVec::new` would create.
+    unsafe { MaybeUninit::zeroed().assume_init() }
+}
+
+/// Formats its arguments into a [`GenericArrayString`] of the given capacity, like `format!`
+/// does into a `String`, but without allocating.
+///
+/// The first argument is the capacity, followed by the same arguments as `format!`, which are
+/// passed through to [`format_args!`], so the full formatting syntax is supported, including
+/// named and captured arguments, width and precision. The result is a `Result`, which is an error
+/// if the formatted string doesn't fit; see [`GenericArrayStringExt::generic_format`]. It never
+/// panics on overflow.
+///
+/// ```rust
+/// use generic_arrayvec::gformat;
+/// use generic_arrayvec::typenum::{U4, U32};
+///
+/// let (a, b) = (12, "ab");
+/// let string = gformat!(U32, "{}-{}", a, b).unwrap();
+///
+/// assert_eq!(&string[..], "12-ab");
+/// assert!(gformat!(U4, "{}-{}", a, b).is_err());
+///
+/// let string = gformat!(U32, "{a:>4}|{:.2}|{name}", 1.0, name = b).unwrap();
+/// assert_eq!(&string[..], "  12|1.00|ab");
+/// ```
+#[macro_export]
+macro_rules! gformat {
+    ($capacity:ty, $($arg:tt)*) => {
+        <$crate::GenericArrayString<$capacity> as $crate::GenericArrayStringExt<$capacity>>::generic_format(
+            ::core::format_args!($($arg)*),
+        )
+    };
+}
+
+/// An alias of [`gformat!`], for code that reads better naming the result.
+///
+/// ```rust
+/// use generic_arrayvec::gstring;
+/// use generic_arrayvec::typenum::U16;
+///
+/// let id = 7;
+/// let key = gstring!(U16, "user:{}", id).unwrap();
+///
+/// assert_eq!(&key[..], "user:7");
+/// ```
+#[macro_export]
+macro_rules! gstring {
+    ($($arg:tt)*) => {
+        $crate::gformat!($($arg)*)
+    };
+}
+
+/// Concatenates a sequence of vectors into one of the same capacity, preserving their order.
+///
+/// The elements are moved, not cloned.
+///
+/// **Errors** if the elements don't all fit; the elements taken so far are dropped in that case,
+/// and no more vectors are taken from `vecs`.
+///
+/// ```rust
+/// use generic_arrayvec::typenum::U4;
+/// use generic_arrayvec::{concat_all, GenericArrayVec};
+///
+/// let parts: Vec<GenericArrayVec<i32, U4>> = vec![(0..2).collect(), (2..3).collect()];
+/// let joined = concat_all(parts).unwrap();
+///
+/// assert_eq!(&joined[..], &[0, 1, 2]);
+/// ```
+pub fn concat_all<T, N, I>(vecs: I) -> Result<GenericArrayVec<T, N>, CapacityError>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+    I: IntoIterator<Item = GenericArrayVec<T, N>>,
+{
+    let mut joined = GenericArrayVec::new();
+    for vec in vecs {
+        if vec.len() > joined.remaining_capacity() {
+            return Err(CapacityError::new(()));
+        }
+        joined.extend(vec);
+    }
+
+    Ok(joined)
+}
+
 /// Extension trait for [`GenericArrayVec`].
 ///
 /// See its impl on [`GenericArrayVec`] for more info.
@@ -162,13 +516,416 @@ where
     N: Capacity<T>,
     ArrayvecStorage<T, N>: Array,
 {
+    const CAPACITY: usize;
+
     fn generic_from<A>(arr: A) -> GenericArrayVec<T, N>
     where
         A: Into<GenericArray<T, N>>;
 
+    fn generic_from_array_into_iter<const M: usize>(
+        iter: array::IntoIter<T, M>,
+    ) -> Result<GenericArrayVec<T, N>, CapacityError<array::IntoIter<T, M>>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn generic_from_arrays<A, B>(
+        a: GenericArray<T, A>,
+        b: GenericArray<T, B>,
+    ) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        A: ArrayLength<T> + Add<B, Output = N>,
+        B: ArrayLength<T>;
+
+    fn merge_sorted<A, B>(
+        a: GenericArrayVec<T, A>,
+        b: GenericArrayVec<T, B>,
+    ) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Ord,
+        A: Capacity<T> + Add<B, Output = N>,
+        ArrayvecStorage<T, A>: Array<Item = T>,
+        B: Capacity<T>,
+        ArrayvecStorage<T, B>: Array<Item = T>;
+
+    fn interleave<A, B>(
+        a: GenericArrayVec<T, A>,
+        b: GenericArrayVec<T, B>,
+    ) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        A: Capacity<T> + Add<B, Output = N>,
+        ArrayvecStorage<T, A>: Array<Item = T>,
+        B: Capacity<T>,
+        ArrayvecStorage<T, B>: Array<Item = T>;
+
+    /// # Safety
+    ///
+    /// The first `len` elements of `arr` must be initialized, and `len` must not exceed the
+    /// capacity.
+    unsafe fn from_maybe_uninit(
+        arr: GenericArray<MaybeUninit<T>, N>,
+        len: usize,
+    ) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        N: ArrayLength<MaybeUninit<T>>;
+
+    fn generic_from_pattern<K>(pattern: &GenericArray<T, K>) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Copy,
+        K: ArrayLength<T>;
+
     fn into_generic_array(self) -> Result<GenericArray<T, N>, Self>
     where
         Self: Sized;
+
+    fn map_into_generic_array<U>(self) -> Result<GenericArray<U, N>, Self>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        Self: Sized,
+        T: Into<U>,
+        N: ArrayLength<U>;
+
+    fn generic_get(&self, index: usize) -> Option<&T>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn generic_get_mut(&mut self, index: usize) -> Option<&mut T>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn generic_contains(&self, x: &T) -> bool
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: PartialEq;
+
+    fn generic_position<F>(&self, pred: F) -> Option<usize>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&T) -> bool;
+
+    fn generic_count<F>(&self, pred: F) -> usize
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&T) -> bool;
+
+    fn generic_all<F>(&self, pred: F) -> bool
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&T) -> bool;
+
+    fn generic_any<F>(&self, pred: F) -> bool
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&T) -> bool;
+
+    fn generic_max(&self) -> Option<&T>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Ord;
+
+    fn generic_min(&self) -> Option<&T>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Ord;
+
+    fn generic_sum<'a, S>(&'a self) -> S
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: 'a,
+        S: Sum<&'a T>;
+
+    fn reset_zeroed(&mut self)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Copy + Default;
+
+    fn from_native_arrayvec<const M: usize>(vec: ArrayVec<[T; M]>) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        [T; M]: Array<Item = T>;
+
+    fn to_native_arrayvec<const M: usize>(self) -> ArrayVec<[T; M]>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        [T; M]: Array<Item = T>;
+
+    fn generic_swap(&mut self, a: usize, b: usize)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn generic_fill(&mut self, value: T)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Clone;
+
+    fn to_generic_array_prefix<L>(&self) -> Option<GenericArray<T, L>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Clone,
+        L: ArrayLength<T>;
+
+    fn dedup_all(&mut self)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: PartialEq;
+
+    fn with_push(self, element: T) -> Self
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        Self: Sized;
+
+    fn with_extend<I>(self, iter: I) -> Self
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        Self: Sized,
+        I: IntoIterator<Item = T>;
+
+    fn try_with_push(self, element: T) -> Result<Self, CapacityError<(Self, T)>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        Self: Sized;
+
+    fn try_with_extend<I>(self, iter: I) -> Result<Self, CapacityError<(Self, T)>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        Self: Sized,
+        I: IntoIterator<Item = T>;
+
+    fn saturating_extend_from_slice(&mut self, other: &[T]) -> usize
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Copy;
+
+    fn generic_clone_from(&mut self, other: &Self)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Clone;
+
+    fn generic_extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, N, F>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&mut T) -> bool;
+
+    fn into_generic_chunks<L>(self) -> IntoGenericChunks<T, N, L>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        Self: Sized,
+        L: Capacity<T>,
+        ArrayvecStorage<T, L>: Array<Item = T>;
+
+    fn generic_insert_sorted(&mut self, value: T) -> Result<usize, CapacityError<T>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Ord;
+
+    fn generic_spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>]
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    /// # Safety
+    ///
+    /// The `additional` slots just past the current length must have been initialized.
+    unsafe fn commit_len(&mut self, additional: usize) -> Result<(), CapacityError>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    /// # Safety
+    ///
+    /// Every element of `self` must also be a valid `U`, with the same meaning; see the method
+    /// docs.
+    unsafe fn reinterpret<U>(self) -> GenericArrayVec<U, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        Self: Sized,
+        N: Capacity<U>,
+        ArrayvecStorage<U, N>: Array<Item = U>;
+
+    fn push_if_absent(&mut self, value: T) -> Result<bool, CapacityError<T>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: PartialEq;
+
+    fn backing_ptr(&self) -> *const T
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn backing_mut_ptr(&mut self) -> *mut T
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn replace_storage(
+        &mut self,
+        arr: GenericArray<T, N>,
+    ) -> (GenericArray<MaybeUninit<T>, N>, usize)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        N: ArrayLength<MaybeUninit<T>>;
+
+    fn generic_fold<B, F>(self, init: B, f: F) -> B
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        Self: Sized,
+        F: FnMut(B, T) -> B;
+
+    fn with_reported_len(len: usize) -> Result<ExactLenBuilder<T, N>, CapacityError>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn copy_to_slice(&self, dest: &mut [T]) -> usize
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Copy;
+
+    fn into_iter_rev(self) -> Rev<IntoIter<ArrayvecStorage<T, N>>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn left_pad_with(&mut self, fill: T)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Copy;
+
+    fn try_take_generic_array(&mut self) -> Option<GenericArray<T, N>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn join_strings<O>(&self, sep: &str) -> Result<GenericArrayString<O>, CapacityError>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: AsRef<str>,
+        O: Capacity<u8>,
+        ArrayvecStorage<u8, O>: Array<Item = u8>,
+        O::ArrayType: Copy;
+
+    fn fill_ratio(&self) -> f32
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn fill_from_with_remainder<I>(iter: I) -> (GenericArrayVec<T, N>, I::IntoIter)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        I: IntoIterator<Item = T>;
+
+    fn resize_to<M>(self, fill: T) -> GenericArrayVec<T, M>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Clone,
+        M: Capacity<T>,
+        ArrayvecStorage<T, M>: Array<Item = T>;
+
+    fn from_backing_with_len(
+        arr: GenericArray<T, N>,
+        len: usize,
+    ) -> Result<GenericArrayVec<T, N>, CapacityError>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn set(&mut self, index: usize, value: T) -> Result<T, CapacityError<T>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn drain_while<F>(&mut self, pred: F) -> Drain<'_, ArrayvecStorage<T, N>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&T) -> bool;
+
+    fn row<K>(&self, i: usize) -> Option<&GenericArray<T, K>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        K: ArrayLength<T>;
+
+    fn row_mut<K>(&mut self, i: usize) -> Option<&mut GenericArray<T, K>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        K: ArrayLength<T>;
+
+    fn try_rotate_left(&mut self, mid: usize) -> Result<(), OutOfBounds>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn try_rotate_right(&mut self, k: usize) -> Result<(), OutOfBounds>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn extend_all_or_nothing<I>(&mut self, iter: I) -> Result<(), CapacityError>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        I: IntoIterator<Item = T>;
+
+    fn generic_swap_with_slice(&mut self, other: &mut [T])
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn generic_starts_with(&self, needle: &[T]) -> bool
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: PartialEq;
+
+    fn generic_ends_with(&self, needle: &[T]) -> bool
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: PartialEq;
+
+    #[allow(clippy::type_complexity)]
+    fn into_generic_arrays<I>(
+        self,
+    ) -> Result<(GenericArray<T, I>, GenericArray<T, Diff<N, I>>), Self>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        Self: Sized,
+        I: ArrayLength<T>,
+        N: Sub<I>,
+        Diff<N, I>: ArrayLength<T>;
+
+    fn sort_dedup(&mut self)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Ord;
+
+    #[cfg(feature = "bytemuck")]
+    fn as_bytes(&self) -> &[u8]
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: bytemuck::Pod;
+
+    #[cfg(feature = "bytemuck")]
+    fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: bytemuck::Pod;
+
+    fn coalesce<F>(&mut self, f: F)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&mut T, T) -> Result<(), T>;
+
+    fn generic_iter(&self) -> slice::Iter<'_, T>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>;
+
+    fn capacity_typenum(&self) -> N
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        N: Default;
+
+    fn split_off_after<F>(&mut self, pred: F) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&T) -> bool;
+
+    fn as_generic_array_chunks<C>(&self) -> Option<&[GenericArray<T, C>]>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        C: ArrayLength<T>;
+
+    fn as_generic_array_chunks_mut<C>(&mut self) -> Option<&mut [GenericArray<T, C>]>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        C: ArrayLength<T>;
 }
 
 impl<T, N> GenericArrayVecExt<T, N> for GenericArrayVec<T, N>
@@ -176,41 +933,2138 @@ where
     N: Capacity<T>,
     ArrayvecStorage<T, N>: Array,
 {
-    /// Creates a `GenericArrayVec` from an array or `GenericArray`.
+    /// The capacity of the vector, `N`, as a `usize`.
+    ///
+    /// Like [`capacity`], this is usable in `const` contexts where `N` is a concrete type.
     ///
     /// ```rust
+    /// use generic_arrayvec::typenum::U4;
     /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
     ///
-    /// let vec = GenericArrayVec::generic_from([2, 4, 6, 8]);
+    /// let buf = [0u8; GenericArrayVec::<u8, U4>::CAPACITY];
     ///
-    /// assert_eq!(vec.len(), 4);
-    /// assert_eq!(vec.capacity(), 4);
+    /// assert_eq!(buf.len(), 4);
+    /// ```
+    const CAPACITY: usize = N::USIZE;
+
+    /// Creates a `GenericArrayVec` from an array or `GenericArray`.
+    ///
+    /// The resulting vector is full. The array is moved into the vector's storage as a whole, with
+    /// no per-element cloning or copying, so there's no need for an unchecked constructor here.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([2, 4, 6, 8]);
+    ///
+    /// assert_eq!(vec.len(), 4);
+    /// assert_eq!(vec.capacity(), 4);
     /// ```
     fn generic_from<A>(arr: A) -> GenericArrayVec<T, N>
     where
-        A: Into<GenericArray<T, N>>,
+        A: Into<GenericArray<T, N>>,
+    {
+        let storage: ArrayvecStorage<T, N> = ArrayvecStorage::from(arr.into());
+        GenericArrayVec::from(storage)
+    }
+
+    /// Creates a `GenericArrayVec` from the remaining elements of an array's by-value iterator.
+    ///
+    /// The elements are moved, not cloned, so this works for element types that aren't `Clone`.
+    /// The array's length doesn't need to match the capacity; the vector is only full if it does.
+    ///
+    /// **Errors** if there are more elements left than fit within the capacity. The iterator is
+    /// returned untouched in that case.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let strings = IntoIterator::into_iter([String::from("a"), String::from("b")]);
+    /// let vec = GenericArrayVec::<String, U4>::generic_from_array_into_iter(strings).unwrap();
+    ///
+    /// assert_eq!(&vec[..], &["a", "b"]);
+    /// assert_eq!(vec.capacity(), 4);
+    /// ```
+    fn generic_from_array_into_iter<const M: usize>(
+        iter: array::IntoIter<T, M>,
+    ) -> Result<GenericArrayVec<T, N>, CapacityError<array::IntoIter<T, M>>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        if iter.len() > N::USIZE {
+            return Err(CapacityError::new(iter));
+        }
+
+        Ok(iter.collect())
+    }
+
+    /// Creates a full vector holding the elements of `a` followed by those of `b`.
+    ///
+    /// The capacity is the sum of the arrays' lengths, so the result is always full. The
+    /// elements are moved, not cloned.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::generic_array::GenericArray;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let a = GenericArray::from([1, 2]);
+    /// let b = GenericArray::from([3, 4, 5]);
+    /// let vec = GenericArrayVec::generic_from_arrays(a, b);
+    ///
+    /// assert_eq!(&vec[..], &[1, 2, 3, 4, 5]);
+    /// assert!(vec.is_full());
+    /// ```
+    fn generic_from_arrays<A, B>(
+        a: GenericArray<T, A>,
+        b: GenericArray<T, B>,
+    ) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        A: ArrayLength<T> + Add<B, Output = N>,
+        B: ArrayLength<T>,
+    {
+        GenericArrayVec::generic_from(a.concat(b))
+    }
+
+    /// Merges two sorted vectors into one sorted vector, whose capacity is the sum of theirs.
+    ///
+    /// The merge is stable: equal elements keep their relative order, with those from `a` coming
+    /// before those from `b`. The elements are moved, not cloned. If either input isn't sorted,
+    /// the output will hold all elements, in an unspecified order.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U2, U3};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let a = GenericArrayVec::<_, U2>::generic_from([1, 4]);
+    /// let b = GenericArrayVec::<_, U3>::generic_from([2, 3, 5]);
+    /// let merged = GenericArrayVec::merge_sorted(a, b);
+    ///
+    /// assert_eq!(&merged[..], &[1, 2, 3, 4, 5]);
+    /// assert_eq!(merged.capacity(), 5);
+    /// ```
+    fn merge_sorted<A, B>(
+        a: GenericArrayVec<T, A>,
+        b: GenericArrayVec<T, B>,
+    ) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Ord,
+        A: Capacity<T> + Add<B, Output = N>,
+        ArrayvecStorage<T, A>: Array<Item = T>,
+        B: Capacity<T>,
+        ArrayvecStorage<T, B>: Array<Item = T>,
+    {
+        let mut merged = GenericArrayVec::new();
+        let mut a = a.into_iter().peekable();
+        let mut b = b.into_iter().peekable();
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let next = if y < x { b.next() } else { a.next() };
+            merged.extend(next);
+        }
+        merged.extend(a);
+        merged.extend(b);
+
+        merged
+    }
+
+    /// Alternates the elements of two vectors into one, whose capacity is the sum of theirs.
+    ///
+    /// The result starts with the first element of `a`, then the first of `b`, then the second of
+    /// `a`, and so on. Once the shorter vector runs out, the rest of the longer one follows in
+    /// order. The elements are moved, not cloned.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U2, U4};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let a = GenericArrayVec::<_, U2>::generic_from(["a1", "a2"]);
+    /// let b = GenericArrayVec::<_, U4>::generic_from(["b1", "b2", "b3", "b4"]);
+    /// let mixed = GenericArrayVec::interleave(a, b);
+    ///
+    /// assert_eq!(&mixed[..], &["a1", "b1", "a2", "b2", "b3", "b4"]);
+    /// assert_eq!(mixed.capacity(), 6);
+    /// ```
+    fn interleave<A, B>(a: GenericArrayVec<T, A>, b: GenericArrayVec<T, B>) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        A: Capacity<T> + Add<B, Output = N>,
+        ArrayvecStorage<T, A>: Array<Item = T>,
+        B: Capacity<T>,
+        ArrayvecStorage<T, B>: Array<Item = T>,
+    {
+        let mut mixed = GenericArrayVec::new();
+        let mut a = a.into_iter();
+        let mut b = b.into_iter();
+        loop {
+            match (a.next(), b.next()) {
+                (Some(x), Some(y)) => {
+                    mixed.push(x);
+                    mixed.push(y);
+                }
+                (x, y) => {
+                    // At most one of the two is left, with at most one element already taken.
+                    mixed.extend(x);
+                    mixed.extend(y);
+                    break;
+                }
+            }
+        }
+        mixed.extend(a);
+        mixed.extend(b);
+
+        mixed
+    }
+
+    /// Creates a `GenericArrayVec` of length `len` from a partially initialized `GenericArray`.
+    ///
+    /// The first `len` elements are moved into the new vector; the rest of `arr` is ignored.
+    ///
+    /// # Safety
+    ///
+    /// The first `len` elements of `arr` must be initialized, and `len` must not exceed the
+    /// capacity. The latter is checked with a `debug_assert!`.
+    ///
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use generic_arrayvec::generic_array::sequence::GenericSequence;
+    /// use generic_arrayvec::generic_array::GenericArray;
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut arr = GenericArray::<MaybeUninit<String>, U4>::generate(|_| MaybeUninit::uninit());
+    /// arr[0] = MaybeUninit::new(String::from("a"));
+    /// arr[1] = MaybeUninit::new(String::from("b"));
+    ///
+    /// let vec = unsafe { GenericArrayVec::<String, U4>::from_maybe_uninit(arr, 2) };
+    /// assert_eq!(&vec[..], &["a", "b"]);
+    /// ```
+    unsafe fn from_maybe_uninit(
+        arr: GenericArray<MaybeUninit<T>, N>,
+        len: usize,
+    ) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        N: ArrayLength<MaybeUninit<T>>,
+    {
+        debug_assert!(len <= N::USIZE);
+
+        let mut vec = GenericArrayVec::new();
+        ptr::copy_nonoverlapping(arr.as_ptr() as *const T, vec.as_mut_ptr(), len);
+        vec.set_len(len);
+
+        vec
+    }
+
+    /// Creates a full `GenericArrayVec` by repeating `pattern` until the capacity is reached.
+    ///
+    /// The last repetition is cut short if the capacity isn't a multiple of the pattern's length.
+    /// An empty pattern produces an empty vector.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::generic_array::GenericArray;
+    /// use generic_arrayvec::typenum::U5;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let pattern = GenericArray::from([0xa, 0xb]);
+    /// let vec = GenericArrayVec::<u8, U5>::generic_from_pattern(&pattern);
+    ///
+    /// assert_eq!(&vec[..], &[0xa, 0xb, 0xa, 0xb, 0xa]);
+    /// ```
+    fn generic_from_pattern<K>(pattern: &GenericArray<T, K>) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Copy,
+        K: ArrayLength<T>,
+    {
+        pattern.iter().copied().cycle().take(N::USIZE).collect()
+    }
+
+    /// Returns the inner `GenericArray`, if `self` is full to its capacity.
+    ///
+    /// **Errors** if `self` is not filled to capacity.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U5;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::<i32, U5>::new();
+    /// vec.push(0);
+    /// vec.extend(1..5);
+    ///
+    /// let generic_array = vec.into_generic_array().unwrap();
+    ///
+    /// assert_eq!(&*generic_array, &[0, 1, 2, 3, 4][..]);
+    /// ```
+    fn into_generic_array(self) -> Result<GenericArray<T, N>, Self> {
+        Ok(self.into_inner()?.into_inner())
+    }
+
+    /// Converts each element with [`Into`], returning them as a `GenericArray`, if `self` is full
+    /// to its capacity.
+    ///
+    /// **Errors** if `self` is not filled to capacity, returning it unchanged.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::generic_array::GenericArray;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([1u8, 2, 255]);
+    /// let wide: GenericArray<u16, _> = vec.map_into_generic_array().unwrap();
+    ///
+    /// assert_eq!(wide, GenericArray::from([1u16, 2, 255]));
+    /// ```
+    fn map_into_generic_array<U>(self) -> Result<GenericArray<U, N>, Self>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Into<U>,
+        N: ArrayLength<U>,
+    {
+        Ok(self.into_generic_array()?.map(Into::into))
+    }
+
+    /// Returns a reference to the element at position `index`, or `None` if it's out of bounds.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([1, 2, 3]);
+    ///
+    /// assert_eq!(vec.generic_get(1), Some(&2));
+    /// assert_eq!(vec.generic_get(3), None);
+    /// ```
+    fn generic_get(&self, index: usize) -> Option<&T>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        self.as_slice().get(index)
+    }
+
+    /// Returns a mutable reference to the element at position `index`, or `None` if it's out of
+    /// bounds.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::generic_from([1, 2, 3]);
+    /// *vec.generic_get_mut(1).unwrap() = 20;
+    ///
+    /// assert_eq!(&vec[..], &[1, 20, 3]);
+    /// assert_eq!(vec.generic_get_mut(3), None);
+    /// ```
+    fn generic_get_mut(&mut self, index: usize) -> Option<&mut T>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    /// Returns `true` if the vector contains an element equal to `x`.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([1, 2, 3]);
+    ///
+    /// assert!(vec.generic_contains(&2));
+    /// assert!(!vec.generic_contains(&4));
+    /// ```
+    fn generic_contains(&self, x: &T) -> bool
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: PartialEq,
+    {
+        self.as_slice().contains(x)
+    }
+
+    /// Returns the index of the first element matching `pred`, or `None` if there is none.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([1, 2, 3]);
+    ///
+    /// assert_eq!(vec.generic_position(|&x| x > 1), Some(1));
+    /// assert_eq!(vec.generic_position(|&x| x > 3), None);
+    /// ```
+    fn generic_position<F>(&self, pred: F) -> Option<usize>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&T) -> bool,
+    {
+        self.as_slice().iter().position(pred)
+    }
+
+    /// Returns the number of elements matching `pred`.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(vec.generic_count(|&x| x % 2 == 0), 2);
+    /// ```
+    fn generic_count<F>(&self, mut pred: F) -> usize
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&T) -> bool,
+    {
+        self.as_slice().iter().filter(|x| pred(x)).count()
+    }
+
+    /// Returns `true` if every element matches `pred`, or if the vector is empty.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([1, 2, 3]);
+    ///
+    /// assert!(vec.generic_all(|&x| x > 0));
+    /// assert!(!vec.generic_all(|&x| x > 1));
+    /// ```
+    fn generic_all<F>(&self, pred: F) -> bool
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&T) -> bool,
+    {
+        self.as_slice().iter().all(pred)
+    }
+
+    /// Returns `true` if any element matches `pred`. Returns `false` for an empty vector.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([1, 2, 3]);
+    ///
+    /// assert!(vec.generic_any(|&x| x > 2));
+    /// assert!(!vec.generic_any(|&x| x > 3));
+    /// ```
+    fn generic_any<F>(&self, pred: F) -> bool
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&T) -> bool,
+    {
+        self.as_slice().iter().any(pred)
+    }
+
+    /// Returns the largest element, or `None` if the vector is empty. If several elements are
+    /// equally large, the last one is returned.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([3, 1, 4, 1, 5]);
+    ///
+    /// assert_eq!(vec.generic_max(), Some(&5));
+    /// ```
+    fn generic_max(&self) -> Option<&T>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Ord,
+    {
+        self.as_slice().iter().max()
+    }
+
+    /// Returns the smallest element, or `None` if the vector is empty. If several elements are
+    /// equally small, the first one is returned.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([3, 1, 4, 1, 5]);
+    ///
+    /// assert_eq!(vec.generic_min(), Some(&1));
+    /// ```
+    fn generic_min(&self) -> Option<&T>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Ord,
+    {
+        self.as_slice().iter().min()
+    }
+
+    /// Sums the elements. An empty vector sums to zero, or whatever `S`'s identity is.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([1, 2, 3]);
+    /// assert_eq!(vec.generic_sum::<i32>(), 6);
+    ///
+    /// let empty = GenericArrayVec::<i32, U4>::new();
+    /// assert_eq!(empty.generic_sum::<i32>(), 0);
+    /// ```
+    fn generic_sum<'a, S>(&'a self) -> S
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: 'a,
+        S: Sum<&'a T>,
+    {
+        self.as_slice().iter().sum()
+    }
+
+    /// Clears the vector, then overwrites its entire backing storage with `T::default()` (zero,
+    /// for integers).
+    ///
+    /// Unlike [`clear`](ArrayVec::clear), which only drops the elements and sets the length to
+    /// zero, this also wipes the spare capacity, so that no old data is left behind in memory. The
+    /// writes are volatile, so they won't be optimized away.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut secret = GenericArrayVec::generic_from(*b"hunter2");
+    /// secret.reset_zeroed();
+    ///
+    /// assert!(secret.is_empty());
+    /// ```
+    fn reset_zeroed(&mut self)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Copy + Default,
+    {
+        self.clear();
+
+        let ptr = self.as_mut_ptr();
+        for i in 0..N::USIZE {
+            // SAFETY: `i` is within the backing storage, and since `T: Copy`, overwriting the old
+            // value can't skip a drop.
+            unsafe { ptr::write_volatile(ptr.add(i), T::default()) };
+        }
+    }
+
+    /// Converts an arrayvec `ArrayVec<[T; M]>`, backed by a regular array, into a
+    /// `GenericArrayVec` of the same capacity.
+    ///
+    /// The elements are moved and the length is preserved. `M` must equal `N::USIZE`; this is
+    /// checked at compile time.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::arrayvec::ArrayVec;
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut native = ArrayVec::<[i32; 4]>::new();
+    /// native.push(1);
+    ///
+    /// let vec = GenericArrayVec::<i32, U4>::from_native_arrayvec(native);
+    /// assert_eq!(&vec[..], &[1]);
+    /// ```
+    fn from_native_arrayvec<const M: usize>(vec: ArrayVec<[T; M]>) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        [T; M]: Array<Item = T>,
+    {
+        SameCapacity::<N, M>::check();
+
+        vec.into_iter().collect()
+    }
+
+    /// Converts `self` into an arrayvec `ArrayVec<[T; M]>` of the same capacity, backed by a
+    /// regular array.
+    ///
+    /// The elements are moved and the length is preserved. `M` must equal `N::USIZE`; this is
+    /// checked at compile time.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::arrayvec::ArrayVec;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([1, 2, 3]);
+    /// let native: ArrayVec<[i32; 3]> = vec.to_native_arrayvec();
+    ///
+    /// assert_eq!(&native[..], &[1, 2, 3]);
+    /// ```
+    fn to_native_arrayvec<const M: usize>(self) -> ArrayVec<[T; M]>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        [T; M]: Array<Item = T>,
+    {
+        SameCapacity::<N, M>::check();
+
+        self.into_iter().collect()
+    }
+
+    /// Swaps the elements at indices `a` and `b`.
+    ///
+    /// **Panics** if `a` or `b` are out of bounds.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::generic_from([1, 2, 3]);
+    /// vec.generic_swap(0, 2);
+    ///
+    /// assert_eq!(&vec[..], &[3, 2, 1]);
+    /// ```
+    fn generic_swap(&mut self, a: usize, b: usize)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        self.as_mut_slice().swap(a, b)
+    }
+
+    /// Overwrites every element with a clone of `value`.
+    ///
+    /// Only the elements currently in the vector are overwritten; the length doesn't change.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec: GenericArrayVec<i32, U4> = (0..2).collect();
+    /// vec.generic_fill(7);
+    ///
+    /// assert_eq!(&vec[..], &[7, 7]);
+    /// ```
+    fn generic_fill(&mut self, value: T)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Clone,
+    {
+        self.as_mut_slice().fill(value)
+    }
+
+    /// Returns a `GenericArray` holding clones of the first `L` elements, or `None` if the vector
+    /// has fewer than `L` elements.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U2, U4, U8};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec: GenericArrayVec<i32, U8> = (0..3).collect();
+    ///
+    /// assert_eq!(&*vec.to_generic_array_prefix::<U2>().unwrap(), &[0, 1]);
+    /// assert_eq!(vec.to_generic_array_prefix::<U4>(), None);
+    /// ```
+    fn to_generic_array_prefix<L>(&self) -> Option<GenericArray<T, L>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Clone,
+        L: ArrayLength<T>,
+    {
+        let prefix = self.as_slice().get(..L::USIZE)?;
+
+        Some(GenericArray::clone_from_slice(prefix))
+    }
+
+    /// Removes all duplicate elements, keeping only the first occurrence of each, and preserving
+    /// the order of the kept elements.
+    ///
+    /// Unlike a consecutive dedup, duplicates needn't be adjacent. This compares every element
+    /// with the ones before it, so it takes O(n²) time, but it needs no allocation or hashing; for
+    /// the small buffers this crate is meant for that's often faster than a `HashSet` anyway.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::generic_from([3, 1, 3, 2, 1]);
+    /// vec.dedup_all();
+    ///
+    /// assert_eq!(&vec[..], &[3, 1, 2]);
+    /// ```
+    fn dedup_all(&mut self)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: PartialEq,
+    {
+        let mut i = 1;
+        while i < self.len() {
+            if self[..i].contains(&self[i]) {
+                self.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    /// Appends an element to the back of the vector and returns the vector, for building one in
+    /// a single expression.
+    ///
+    /// **Panics** if the vector is full.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::<i32, U4>::new()
+    ///     .with_push(1)
+    ///     .with_extend(2..4);
+    ///
+    /// assert_eq!(&vec[..], &[1, 2, 3]);
+    /// ```
+    fn with_push(mut self, element: T) -> Self
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        self.push(element);
+        self
+    }
+
+    /// Appends the elements of `iter` to the vector and returns the vector, for building one in a
+    /// single expression.
+    ///
+    /// **Panics** if the vector overflows. Plain [`Extend::extend`] silently drops the elements
+    /// that don't fit instead.
+    fn with_extend<I>(mut self, iter: I) -> Self
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        I: IntoIterator<Item = T>,
+    {
+        if self.try_extend(iter).is_err() {
+            panic!("with_extend: capacity {} exceeded", N::USIZE);
+        }
+
+        self
+    }
+
+    /// Appends an element to the back of the vector and returns the vector, for building one in
+    /// a single expression.
+    ///
+    /// **Errors** if the vector is full, returning both the vector and the element.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U2;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let result = GenericArrayVec::<i32, U2>::new()
+    ///     .try_with_push(1)
+    ///     .and_then(|vec| vec.try_with_extend(2..5));
+    ///
+    /// let (vec, element) = result.unwrap_err().element();
+    /// assert_eq!(&vec[..], &[1, 2]);
+    /// assert_eq!(element, 3);
+    /// ```
+    fn try_with_push(mut self, element: T) -> Result<Self, CapacityError<(Self, T)>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        match self.try_push(element) {
+            Ok(()) => Ok(self),
+            Err(err) => Err(CapacityError::new((self, err.element()))),
+        }
+    }
+
+    /// Appends the elements of `iter` to the vector and returns the vector, for building one in a
+    /// single expression.
+    ///
+    /// **Errors** at the first element that doesn't fit, returning both the partially extended
+    /// vector and that element. No more are taken from `iter`; pass `iter.by_ref()` to get at the
+    /// ones left over.
+    fn try_with_extend<I>(mut self, iter: I) -> Result<Self, CapacityError<(Self, T)>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        I: IntoIterator<Item = T>,
+    {
+        match self.try_extend(iter) {
+            Ok(()) => Ok(self),
+            Err(err) => Err(CapacityError::new((self, err.element()))),
+        }
+    }
+
+    /// Copies as many elements from the front of `other` as fit to the back of the vector,
+    /// returning how many were copied.
+    ///
+    /// Unlike [`try_extend_from_slice`](ArrayVec::try_extend_from_slice), this never errors; if
+    /// `other` doesn't fit, its longest prefix that does is copied.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::<u8, U4>::new();
+    ///
+    /// assert_eq!(vec.saturating_extend_from_slice(&[1, 2, 3]), 3);
+    /// assert_eq!(vec.saturating_extend_from_slice(&[4, 5, 6]), 1);
+    /// assert_eq!(&vec[..], &[1, 2, 3, 4]);
+    /// ```
+    fn saturating_extend_from_slice(&mut self, other: &[T]) -> usize
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Copy,
+    {
+        let count = other.len().min(self.remaining_capacity());
+        self.try_extend_from_slice(&other[..count])
+            .expect("the prefix fits within the remaining capacity");
+
+        count
+    }
+
+    /// Makes `self` a clone of `other`, reusing its existing elements.
+    ///
+    /// The elements `self` already has are overwritten with
+    /// [`clone_from`](Clone::clone_from), and then the remaining elements are either cloned onto
+    /// the end or dropped, depending on which vector is longer. This is what arrayvec's
+    /// `Clone::clone_from` does too; it's offered here so generic code can call it through this
+    /// trait.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec: GenericArrayVec<String, U4> = vec!["a".to_owned(); 3].into_iter().collect();
+    /// let other: GenericArrayVec<String, U4> = vec!["b".to_owned(); 2].into_iter().collect();
+    /// vec.generic_clone_from(&other);
+    ///
+    /// assert_eq!(vec, other);
+    /// ```
+    fn generic_clone_from(&mut self, other: &Self)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Clone,
+    {
+        self.clone_from(other)
+    }
+
+    /// Returns an iterator that removes and yields the elements for which `pred` returns `true`,
+    /// front to back, shifting the kept elements down to close the gaps.
+    ///
+    /// `pred` is given a mutable reference, so it can also modify the elements it keeps. If the
+    /// iterator is dropped before it's exhausted, the elements it hasn't reached yet are kept
+    /// without being passed to `pred`. If it's leaked instead, e.g. with [`mem::forget`], the
+    /// vector may lose any number of its elements, though it stays valid.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U8;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec: GenericArrayVec<i32, U8> = (1..=6).collect();
+    /// let evens: Vec<_> = vec.generic_extract_if(|x| *x % 2 == 0).collect();
+    ///
+    /// assert_eq!(evens, [2, 4, 6]);
+    /// assert_eq!(&vec[..], &[1, 3, 5]);
+    /// ```
+    fn generic_extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, N, F>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&mut T) -> bool,
+    {
+        ExtractIf::new(self, pred)
+    }
+
+    /// Returns an iterator that moves the elements out in batches, each a vector of capacity `L`.
+    ///
+    /// Every batch is full except possibly the last, which holds the remaining elements. If the
+    /// iterator is dropped before it's exhausted, the elements it hasn't yielded are dropped with
+    /// it.
+    ///
+    /// **Panics** if `L` is zero.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U2, U8};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec: GenericArrayVec<i32, U8> = (0..5).collect();
+    /// let chunks: Vec<_> = vec.into_generic_chunks::<U2>().collect();
+    ///
+    /// assert_eq!(chunks.len(), 3);
+    /// assert_eq!(&chunks[0][..], &[0, 1]);
+    /// assert_eq!(&chunks[1][..], &[2, 3]);
+    /// assert_eq!(&chunks[2][..], &[4]);
+    /// ```
+    fn into_generic_chunks<L>(self) -> IntoGenericChunks<T, N, L>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        L: Capacity<T>,
+        ArrayvecStorage<T, L>: Array<Item = T>,
+    {
+        IntoGenericChunks::new(self)
+    }
+
+    /// Inserts `value` into a sorted vector, keeping it sorted, and returns the index it was
+    /// inserted at.
+    ///
+    /// The position is found with a binary search. If there are elements equal to `value`, it's
+    /// inserted after them. If the vector isn't sorted, the position is unspecified.
+    ///
+    /// **Errors** if the vector is full, returning the value.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::<i32, U4>::new();
+    /// vec.extend([10, 20, 30].iter().copied());
+    ///
+    /// assert_eq!(vec.generic_insert_sorted(25), Ok(2));
+    /// assert_eq!(&vec[..], &[10, 20, 25, 30]);
+    /// assert!(vec.generic_insert_sorted(5).is_err());
+    /// ```
+    fn generic_insert_sorted(&mut self, value: T) -> Result<usize, CapacityError<T>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Ord,
+    {
+        let index = self.as_slice().partition_point(|x| *x <= value);
+        self.try_insert(index, value).map(|()| index)
+    }
+
+    /// Returns the vector's unused capacity, as a slice of possibly uninitialized elements.
+    ///
+    /// After initializing some of these, starting from the front, make them part of the vector
+    /// with [`commit_len`](GenericArrayVecExt::commit_len).
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::<u8, U4>::new();
+    /// vec.push(1);
+    ///
+    /// assert_eq!(vec.generic_spare_capacity_mut().len(), 3);
+    /// ```
+    fn generic_spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>]
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        let len = self.len();
+        let spare = self.capacity() - len;
+        // SAFETY: the slots from `len` up to the capacity are within the storage, and viewing
+        // them as `MaybeUninit` makes no claim about whether they're initialized.
+        unsafe {
+            slice::from_raw_parts_mut(self.as_mut_ptr().add(len) as *mut MaybeUninit<T>, spare)
+        }
+    }
+
+    /// Increases the length by `additional`, after that many spare slots have been initialized,
+    /// e.g. through [`generic_spare_capacity_mut`](GenericArrayVecExt::generic_spare_capacity_mut).
+    ///
+    /// This is [`set_len`](ArrayVec::set_len), but relative to the current length and checked
+    /// against the capacity.
+    ///
+    /// **Errors** if the new length would exceed the capacity, leaving the length unchanged.
+    ///
+    /// # Safety
+    ///
+    /// The `additional` slots just past the current length must have been initialized. If fewer
+    /// were, the vector will treat uninitialized memory as elements.
+    ///
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::<u8, U4>::new();
+    /// for (slot, byte) in vec.generic_spare_capacity_mut().iter_mut().zip(b"hi") {
+    ///     *slot = MaybeUninit::new(*byte);
+    /// }
+    /// // SAFETY: two slots were initialized above.
+    /// unsafe { vec.commit_len(2).unwrap() };
+    ///
+    /// assert_eq!(&vec[..], b"hi");
+    /// assert!(unsafe { vec.commit_len(3) }.is_err());
+    /// ```
+    unsafe fn commit_len(&mut self, additional: usize) -> Result<(), CapacityError>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        if additional > self.remaining_capacity() {
+            return Err(CapacityError::new(()));
+        }
+
+        // SAFETY: the new length is within the capacity, and the caller guarantees the slots it
+        // adds are initialized.
+        self.set_len(self.len() + additional);
+
+        Ok(())
+    }
+
+    /// Reinterprets each element as a `U`, keeping the length, without converting them.
+    ///
+    /// This is for types with compatible layouts, e.g. `[u8; 4]` and `u32`. The bytes of each
+    /// element are copied as they are, so `U` may have a stricter alignment than `T`. `T` and `U`
+    /// must have the same size; this is checked at compile time.
+    ///
+    /// # Safety
+    ///
+    /// The bytes of every element of `self` must be a valid `U`. This rules out, for example,
+    /// reinterpreting `u8`s greater than 1 as `bool`s. If `U` has invariants beyond its bit
+    /// pattern, the caller must ensure the elements uphold them. The elements are not dropped as
+    /// `T`s; they'll only be dropped as `U`s, so reinterpreting types with drop glue is only
+    /// sound if that's equivalent.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let words = GenericArrayVec::generic_from([1u32.to_ne_bytes(), 2u32.to_ne_bytes()]);
+    ///
+    /// // SAFETY: every 4-byte pattern is a valid `u32`.
+    /// let words: GenericArrayVec<u32, _> = unsafe { words.reinterpret() };
+    ///
+    /// assert_eq!(&words[..], &[1, 2]);
+    /// ```
+    ///
+    /// Elements of different sizes are rejected:
+    ///
+    /// ```rust,compile_fail
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let bytes = GenericArrayVec::generic_from([1u8, 2]);
+    /// let words: GenericArrayVec<u32, _> = unsafe { bytes.reinterpret() };
+    /// ```
+    unsafe fn reinterpret<U>(mut self) -> GenericArrayVec<U, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        N: Capacity<U>,
+        ArrayvecStorage<U, N>: Array<Item = U>,
+    {
+        SameSize::<T, U>::check();
+
+        let len = self.len();
+        let mut reinterpreted = GenericArrayVec::<U, N>::new();
+        // SAFETY: both vectors have room for `len` elements of the same size. The copy is done
+        // bytewise, so it doesn't matter that the source may be less aligned than a `U`. The
+        // elements now belong to `reinterpreted`, so `self` forgets them, and the caller
+        // guarantees they're valid `U`s.
+        ptr::copy_nonoverlapping(
+            self.as_ptr() as *const u8,
+            reinterpreted.as_mut_ptr() as *mut u8,
+            len * size_of::<T>(),
+        );
+        self.set_len(0);
+        reinterpreted.set_len(len);
+
+        reinterpreted
+    }
+
+    /// Appends `value` unless the vector already contains an equal element, returning whether it
+    /// was appended.
+    ///
+    /// This makes the vector usable as a small set. Looking for an equal element is a linear
+    /// scan.
+    ///
+    /// **Errors** if `value` is absent but the vector is full, returning the value.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U2;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut set = GenericArrayVec::<char, U2>::new();
+    ///
+    /// assert_eq!(set.push_if_absent('a'), Ok(true));
+    /// assert_eq!(set.push_if_absent('a'), Ok(false));
+    /// assert_eq!(set.push_if_absent('b'), Ok(true));
+    /// assert!(set.push_if_absent('c').is_err());
+    /// assert_eq!(&set[..], &['a', 'b']);
+    /// ```
+    fn push_if_absent(&mut self, value: T) -> Result<bool, CapacityError<T>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: PartialEq,
+    {
+        if self.as_slice().contains(&value) {
+            return Ok(false);
+        }
+
+        self.try_push(value).map(|()| true)
+    }
+
+    /// Returns a pointer to the start of the backing storage, which has room for `N` elements.
+    ///
+    /// Only the first `len` elements are initialized; the rest of the storage may hold
+    /// uninitialized memory, so it must not be read through this pointer. Reading past `N`
+    /// elements is out of bounds. This is mainly for passing the buffer to foreign functions,
+    /// which expect a pointer and a capacity.
+    ///
+    /// The pointer is valid as long as the vector isn't moved or dropped.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([1, 2]);
+    ///
+    /// assert_eq!(unsafe { *vec.backing_ptr().add(1) }, 2);
+    /// ```
+    fn backing_ptr(&self) -> *const T
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        self.as_ptr()
+    }
+
+    /// Returns a mutable pointer to the start of the backing storage, which has room for `N`
+    /// elements.
+    ///
+    /// As with [`backing_ptr`](GenericArrayVecExt::backing_ptr), only the first `len` elements
+    /// are initialized. Writing to the storage past them is fine, and can be followed by
+    /// [`commit_len`](GenericArrayVecExt::commit_len) to make the written elements part of the
+    /// vector.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::<u8, U4>::new();
+    /// unsafe {
+    ///     // Stands in for a C function that fills a buffer of a given capacity.
+    ///     std::ptr::write_bytes(vec.backing_mut_ptr(), 7, vec.capacity());
+    ///     vec.commit_len(4).unwrap();
+    /// }
+    ///
+    /// assert_eq!(&vec[..], &[7; 4]);
+    /// ```
+    fn backing_mut_ptr(&mut self) -> *mut T
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        self.as_mut_ptr()
+    }
+
+    /// Replaces the whole backing storage with `arr`, leaving the vector full, and returns the old
+    /// storage along with the old length.
+    ///
+    /// This moves the storage in one go, rather than element by element, which suits swapping
+    /// buffers back and forth.
+    ///
+    /// The old storage is returned as an array of [`MaybeUninit`], since only its first `len`
+    /// elements are initialized; the rest are whatever was left in the slots. Those first `len`
+    /// elements are now owned by the caller, who is responsible for dropping them, e.g. with
+    /// [`MaybeUninit::assume_init_drop`]. They're leaked otherwise, which is fine for `Copy`
+    /// types.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U3;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    /// use generic_arrayvec::generic_array::arr;
+    ///
+    /// let mut vec = GenericArrayVec::<i32, U3>::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    ///
+    /// let (old, len) = vec.replace_storage(arr![i32; 7, 8, 9]);
+    /// let old: Vec<i32> = old[..len].iter().map(|x| unsafe { x.assume_init() }).collect();
+    ///
+    /// assert_eq!(old, [1, 2]);
+    /// assert_eq!(&vec[..], &[7, 8, 9]);
+    /// ```
+    fn replace_storage(
+        &mut self,
+        arr: GenericArray<T, N>,
+    ) -> (GenericArray<MaybeUninit<T>, N>, usize)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        N: ArrayLength<MaybeUninit<T>>,
+    {
+        let old = ManuallyDrop::new(mem::replace(self, GenericArrayVec::generic_from(arr)));
+        let len = old.len();
+        // SAFETY: the vector's storage starts at `as_ptr` and holds `N` slots of `T`, which has the
+        // same layout as `N` slots of `MaybeUninit<T>`. `old` is never dropped, so ownership of its
+        // initialized elements passes to the returned array.
+        let storage = unsafe { ptr::read(old.as_ptr() as *const GenericArray<MaybeUninit<T>, N>) };
+
+        (storage, len)
+    }
+
+    /// Consumes the vector, folding its elements into an accumulator from front to back.
+    ///
+    /// Each element is moved into `f` along with the accumulator so far, starting from `init`. If
+    /// `f` panics, the elements it hasn't been given yet are still dropped.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let words = GenericArrayVec::generic_from([String::from("fixed"), String::from("vec")]);
+    ///
+    /// let joined = words.generic_fold(String::new(), |mut acc, word| {
+    ///     acc.push_str(&word);
+    ///     acc
+    /// });
+    /// assert_eq!(joined, "fixedvec");
+    /// ```
+    fn generic_fold<B, F>(self, init: B, f: F) -> B
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(B, T) -> B,
+    {
+        self.into_iter().fold(init, f)
+    }
+
+    /// Returns a builder for a vector of exactly `len` elements, such as a length read from a
+    /// message header.
+    ///
+    /// **Errors** up front if `len` is greater than the capacity. See [`ExactLenBuilder`] for how
+    /// it's filled.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let header_len = 3;
+    /// let mut builder = GenericArrayVec::<u8, U4>::with_reported_len(header_len).unwrap();
+    /// for byte in b"abc" {
+    ///     builder.push(*byte).unwrap();
+    /// }
+    /// assert_eq!(&builder.finish().unwrap()[..], b"abc");
+    ///
+    /// assert!(GenericArrayVec::<u8, U4>::with_reported_len(5).is_err());
+    /// ```
+    fn with_reported_len(len: usize) -> Result<ExactLenBuilder<T, N>, CapacityError>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        ExactLenBuilder::new(len)
+    }
+
+    /// Copies as many elements as fit from the front of the vector into `dest`, returning how
+    /// many were copied.
+    ///
+    /// That's the smaller of the vector's length and `dest`'s, copied in one go. The rest of
+    /// `dest` is left as it was.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([1, 2, 3]);
+    ///
+    /// let mut short = [0; 2];
+    /// assert_eq!(vec.copy_to_slice(&mut short), 2);
+    /// assert_eq!(short, [1, 2]);
+    ///
+    /// let mut long = [0; 5];
+    /// assert_eq!(vec.copy_to_slice(&mut long), 3);
+    /// assert_eq!(long, [1, 2, 3, 0, 0]);
+    /// ```
+    fn copy_to_slice(&self, dest: &mut [T]) -> usize
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Copy,
+    {
+        let count = self.len().min(dest.len());
+        dest[..count].copy_from_slice(&self[..count]);
+
+        count
+    }
+
+    /// Returns an iterator that moves the elements out from back to front, for draining the
+    /// vector as a stack.
+    ///
+    /// If the iterator is dropped before it's exhausted, the elements it hasn't yielded are
+    /// dropped with it.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([1, 2, 3]);
+    ///
+    /// assert!(vec.into_iter_rev().eq([3, 2, 1].iter().copied()));
+    /// ```
+    fn into_iter_rev(self) -> Rev<IntoIter<ArrayvecStorage<T, N>>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        self.into_iter().rev()
+    }
+
+    /// Fills the vector to its capacity by inserting copies of `fill` at the front, shifting the
+    /// existing elements to the back.
+    ///
+    /// This is the fixed-width counterpart to zero-padding a big-endian number. Afterwards the
+    /// vector is always full; nothing changes if it already was.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::<u8, U4>::new();
+    /// vec.push(0x12);
+    /// vec.push(0x34);
+    /// vec.left_pad_with(0);
+    ///
+    /// assert_eq!(&vec[..], &[0, 0, 0x12, 0x34]);
+    /// ```
+    fn left_pad_with(&mut self, fill: T)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Copy,
+    {
+        let pad = self.remaining_capacity();
+        self.extend(core::iter::repeat(fill).take(pad));
+        self.rotate_right(pad);
+    }
+
+    /// Moves the elements out as a `GenericArray` if the vector is full, leaving it empty and
+    /// ready for reuse.
+    ///
+    /// Returns `None` if the vector isn't full, leaving it unchanged. Unlike
+    /// [`into_generic_array`](GenericArrayVecExt::into_generic_array), this doesn't consume the
+    /// vector either way.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U2;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::<i32, U2>::new();
+    /// vec.push(1);
+    /// assert_eq!(vec.try_take_generic_array(), None);
+    ///
+    /// vec.push(2);
+    /// assert_eq!(&*vec.try_take_generic_array().unwrap(), &[1, 2]);
+    /// assert!(vec.is_empty());
+    /// ```
+    fn try_take_generic_array(&mut self) -> Option<GenericArray<T, N>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        if !self.is_full() {
+            return None;
+        }
+
+        mem::take(self).into_generic_array().ok()
+    }
+
+    /// Joins the elements into a string of capacity `O`, with `sep` between each pair, like
+    /// [`slice::join`] but without allocating.
+    ///
+    /// **Errors** if the joined string, separators included, doesn't fit within `O`. The length
+    /// is checked before anything is copied.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U4, U8, U16};
+    /// use generic_arrayvec::{
+    ///     GenericArrayString, GenericArrayStringExt, GenericArrayVec, GenericArrayVecExt,
+    /// };
+    ///
+    /// let mut parts = GenericArrayVec::<GenericArrayString<U4>, U4>::new();
+    /// for part in &["usr", "local", "bin"] {
+    ///     parts.push(GenericArrayString::generic_from_truncating(part));
+    /// }
+    ///
+    /// assert_eq!(&parts.join_strings::<U16>("/").unwrap()[..], "usr/loca/bin");
+    /// assert!(parts.join_strings::<U8>("/").is_err());
+    /// ```
+    ///
+    /// Any element type that's `AsRef<str>` works, not just `GenericArrayString`.
+    fn join_strings<O>(&self, sep: &str) -> Result<GenericArrayString<O>, CapacityError>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: AsRef<str>,
+        O: Capacity<u8>,
+        ArrayvecStorage<u8, O>: Array<Item = u8>,
+        O::ArrayType: Copy,
+    {
+        let seps_len = sep.len().saturating_mul(self.len().saturating_sub(1));
+        let total = self
+            .iter()
+            .fold(seps_len, |total, s| total.saturating_add(s.as_ref().len()));
+        if total > O::USIZE {
+            return Err(CapacityError::new(()));
+        }
+
+        let mut joined = GenericArrayString::new();
+        for (i, s) in self.iter().enumerate() {
+            if i > 0 {
+                joined.push_str(sep);
+            }
+            joined.push_str(s.as_ref());
+        }
+
+        Ok(joined)
+    }
+
+    /// Returns how full the vector is, from `0.0` when empty to `1.0` when full.
+    ///
+    /// A vector of capacity zero always returns `0.0`, since it never holds anything, rather than
+    /// the `NaN` that dividing by its capacity would give.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U0, U4};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::<i32, U4>::new();
+    /// vec.push(1);
+    /// assert_eq!(vec.fill_ratio(), 0.25);
+    ///
+    /// assert_eq!(GenericArrayVec::<i32, U0>::new().fill_ratio(), 0.0);
+    /// ```
+    fn fill_ratio(&self) -> f32
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        if N::USIZE == 0 {
+            return 0.0;
+        }
+
+        self.len() as f32 / N::USIZE as f32
+    }
+
+    /// Creates a vector from the first `N` elements of `iter`, returning it along with the rest of
+    /// the iterator.
+    ///
+    /// Exactly as many elements as fit are taken, so the returned iterator resumes right after
+    /// the last one in the vector. If `iter` runs out first, the vector is left partially filled.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U3;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let (first, rest) = GenericArrayVec::<_, U3>::fill_from_with_remainder(1..=5);
+    /// assert_eq!(&first[..], &[1, 2, 3]);
+    ///
+    /// let (second, mut rest) = GenericArrayVec::<_, U3>::fill_from_with_remainder(rest);
+    /// assert_eq!(&second[..], &[4, 5]);
+    /// assert_eq!(rest.next(), None);
+    /// ```
+    fn fill_from_with_remainder<I>(iter: I) -> (GenericArrayVec<T, N>, I::IntoIter)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        let vec = iter.by_ref().take(N::USIZE).collect();
+
+        (vec, iter)
+    }
+
+    /// Moves the elements into a vector of capacity `M`, truncating or padding with `fill` so that
+    /// it's exactly full.
+    ///
+    /// The result starts with the first `min(len, M)` elements, so this never fails: shrinking
+    /// below the current length drops the elements that don't fit, and growing appends clones of
+    /// `fill` until the length is `M`.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U2, U6};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec = GenericArrayVec::generic_from([1, 2, 3]);
+    ///
+    /// let grown = vec.clone().resize_to::<U6>(0);
+    /// assert_eq!(&grown[..], &[1, 2, 3, 0, 0, 0]);
+    ///
+    /// let shrunk = vec.resize_to::<U2>(0);
+    /// assert_eq!(&shrunk[..], &[1, 2]);
+    /// ```
+    fn resize_to<M>(self, fill: T) -> GenericArrayVec<T, M>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Clone,
+        M: Capacity<T>,
+        ArrayvecStorage<T, M>: Array<Item = T>,
+    {
+        let kept = self.len().min(M::USIZE);
+
+        self.into_iter()
+            .take(kept)
+            .chain(core::iter::repeat(fill).take(M::USIZE - kept))
+            .collect()
+    }
+
+    /// Creates a `GenericArrayVec` holding the first `len` elements of `arr`, such as a buffer
+    /// filled by hardware that reports how much of it is valid.
+    ///
+    /// The elements past `len` are dropped. Since a `GenericArray` is always fully initialized,
+    /// this is safe, unlike [`from_maybe_uninit`](GenericArrayVecExt::from_maybe_uninit).
+    ///
+    /// **Errors** if `len` is greater than the capacity.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::generic_array::GenericArray;
+    /// use generic_arrayvec::typenum::U8;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut dma_buf = GenericArray::<u8, U8>::default();
+    /// dma_buf[..3].copy_from_slice(b"abc");
+    ///
+    /// let vec = GenericArrayVec::from_backing_with_len(dma_buf, 3).unwrap();
+    /// assert_eq!(&vec[..], b"abc");
+    ///
+    /// assert!(GenericArrayVec::from_backing_with_len(dma_buf, 9).is_err());
+    /// ```
+    fn from_backing_with_len(
+        arr: GenericArray<T, N>,
+        len: usize,
+    ) -> Result<GenericArrayVec<T, N>, CapacityError>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        if len > N::USIZE {
+            return Err(CapacityError::new(()));
+        }
+
+        let mut vec = GenericArrayVec::generic_from(arr);
+        vec.truncate(len);
+
+        Ok(vec)
+    }
+
+    /// Replaces the element at position `index` with `value`, returning the old element.
+    ///
+    /// This is the same as assigning through `vec[index]`, except that the displaced element is
+    /// handed back rather than dropped, and it doesn't panic.
+    ///
+    /// **Errors** if `index` is out of bounds, i.e. `index >= len`, returning `value`. Writing
+    /// just past the end isn't allowed either, since there's no old element to return; use
+    /// [`try_push`](arrayvec::ArrayVec::try_push) for that.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::generic_from(["a", "b", "c"]);
+    ///
+    /// assert_eq!(vec.set(1, "x").unwrap(), "b");
+    /// assert_eq!(&vec[..], &["a", "x", "c"]);
+    /// assert_eq!(vec.set(3, "y").unwrap_err().element(), "y");
+    /// ```
+    fn set(&mut self, index: usize, value: T) -> Result<T, CapacityError<T>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        match self.get_mut(index) {
+            Some(slot) => Ok(mem::replace(slot, value)),
+            None => Err(CapacityError::new(value)),
+        }
+    }
+
+    /// Removes the leading run of elements for which `pred` returns `true`, returning them as an
+    /// iterator, and shifts the rest down to the front.
+    ///
+    /// `pred` is called on the elements in order, up to and including the first for which it
+    /// returns `false`, before this returns. Every element it matched is removed, even if the
+    /// iterator is dropped before yielding them all.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut buf = GenericArrayVec::generic_from(*b"  GET /");
+    ///
+    /// assert_eq!(buf.drain_while(|&byte| byte == b' ').count(), 2);
+    /// assert_eq!(&buf[..], b"GET /");
+    /// ```
+    fn drain_while<F>(&mut self, mut pred: F) -> Drain<'_, ArrayvecStorage<T, N>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&T) -> bool,
+    {
+        let run = self.iter().position(|x| !pred(x)).unwrap_or(self.len());
+        self.drain(..run)
+    }
+
+    /// Views the elements as consecutive rows of `K`, returning a reference to row `i`.
+    ///
+    /// Row `i` is the elements from `i * K` up to `(i + 1) * K`, borrowed in place. Returns `None`
+    /// if those aren't all in the vector, including for a trailing partial row.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{Prod, U2, U3};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// // A 2x3 matrix, stored row by row.
+    /// let matrix: GenericArrayVec<i32, Prod<U2, U3>> = (1..=6).collect();
+    ///
+    /// assert_eq!(matrix.row::<U3>(1).unwrap().as_slice(), &[4, 5, 6]);
+    /// assert_eq!(matrix.row::<U3>(2), None);
+    /// ```
+    fn row<K>(&self, i: usize) -> Option<&GenericArray<T, K>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        K: ArrayLength<T>,
+    {
+        let start = i.checked_mul(K::USIZE)?;
+        let row = self.get(start..start.checked_add(K::USIZE)?)?;
+
+        Some(GenericArray::from_slice(row))
+    }
+
+    /// Views the elements as consecutive rows of `K`, returning a mutable reference to row `i`.
+    ///
+    /// See [`row`](GenericArrayVecExt::row) for how rows are laid out.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{Prod, U2};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut matrix: GenericArrayVec<i32, Prod<U2, U2>> = (1..=4).collect();
+    /// matrix.row_mut::<U2>(0).unwrap().swap(0, 1);
+    ///
+    /// assert_eq!(&matrix[..], &[2, 1, 3, 4]);
+    /// ```
+    fn row_mut<K>(&mut self, i: usize) -> Option<&mut GenericArray<T, K>>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        K: ArrayLength<T>,
+    {
+        let start = i.checked_mul(K::USIZE)?;
+        let row = self.get_mut(start..start.checked_add(K::USIZE)?)?;
+
+        Some(GenericArray::from_mut_slice(row))
+    }
+
+    /// Rotates the elements in place so that the element at `mid` becomes the first, like
+    /// [`slice::rotate_left`], but without panicking.
+    ///
+    /// **Errors** if `mid > len`, leaving the vector unchanged.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt, OutOfBounds};
+    ///
+    /// let mut vec = GenericArrayVec::generic_from([1, 2, 3, 4]);
+    /// vec.try_rotate_left(1).unwrap();
+    /// assert_eq!(&vec[..], &[2, 3, 4, 1]);
+    ///
+    /// assert_eq!(vec.try_rotate_left(5), Err(OutOfBounds { index: 5, len: 4 }));
+    /// ```
+    fn try_rotate_left(&mut self, mid: usize) -> Result<(), OutOfBounds>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        let len = self.len();
+        if mid > len {
+            return Err(OutOfBounds { index: mid, len });
+        }
+
+        self.rotate_left(mid);
+        Ok(())
+    }
+
+    /// Rotates the elements in place so that the last `k` become the first, like
+    /// [`slice::rotate_right`], but without panicking.
+    ///
+    /// **Errors** if `k > len`, leaving the vector unchanged.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt, OutOfBounds};
+    ///
+    /// let mut vec = GenericArrayVec::generic_from([1, 2, 3, 4]);
+    /// vec.try_rotate_right(1).unwrap();
+    /// assert_eq!(&vec[..], &[4, 1, 2, 3]);
+    ///
+    /// assert_eq!(vec.try_rotate_right(5), Err(OutOfBounds { index: 5, len: 4 }));
+    /// ```
+    fn try_rotate_right(&mut self, k: usize) -> Result<(), OutOfBounds>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        let len = self.len();
+        if k > len {
+            return Err(OutOfBounds { index: k, len });
+        }
+
+        self.rotate_right(k);
+        Ok(())
+    }
+
+    /// Appends all the elements of `iter`, or none of them if they don't all fit.
+    ///
+    /// The elements are pushed as they come, with no staging area, so any iterator works, with no
+    /// need for `ExactSizeIterator` or `T: Clone`. If one doesn't fit, the ones pushed so far are
+    /// popped off and dropped again.
+    ///
+    /// **Errors** if the elements don't all fit, leaving the vector as it was. `iter` is consumed
+    /// up to and including the first element that doesn't fit, and those elements are dropped.
+    ///
+    /// If `iter` panics, the elements pushed so far are dropped before the panic propagates, so
+    /// the vector is left as it was in that case too.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::<i32, U4>::new();
+    /// vec.extend_all_or_nothing(1..=3).unwrap();
+    ///
+    /// assert!(vec.extend_all_or_nothing(4..=5).is_err());
+    /// assert_eq!(&vec[..], &[1, 2, 3]);
+    /// ```
+    fn extend_all_or_nothing<I>(&mut self, iter: I) -> Result<(), CapacityError>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        I: IntoIterator<Item = T>,
+    {
+        // Truncates the vector to `len` when dropped, including while unwinding.
+        struct TruncateOnDrop<'a, A: Array> {
+            vec: &'a mut ArrayVec<A>,
+            len: usize,
+        }
+
+        impl<'a, A: Array> Drop for TruncateOnDrop<'a, A> {
+            fn drop(&mut self) {
+                self.vec.truncate(self.len);
+            }
+        }
+
+        let len = self.len();
+        let mut guard = TruncateOnDrop { vec: self, len };
+        for element in iter {
+            if guard.vec.try_push(element).is_err() {
+                return Err(CapacityError::new(()));
+            }
+        }
+
+        // Everything fit, so keep it.
+        guard.len = guard.vec.len();
+        Ok(())
+    }
+
+    /// Swaps the elements of the vector with those of `other`, in place.
+    ///
+    /// This is [`slice::swap_with_slice`] over the vector's elements, nameable in generic code
+    /// without going through the slice.
+    ///
+    /// **Panics** if `other.len() != self.len()`.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut front = GenericArrayVec::generic_from([1, 2]);
+    /// let mut back = [3, 4];
+    /// front.generic_swap_with_slice(&mut back);
+    ///
+    /// assert_eq!(&front[..], &[3, 4]);
+    /// assert_eq!(back, [1, 2]);
+    /// ```
+    fn generic_swap_with_slice(&mut self, other: &mut [T])
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        self.as_mut_slice().swap_with_slice(other)
+    }
+
+    /// Returns `true` if `needle` is a prefix of the vector's elements. An empty `needle` always
+    /// is.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let frame = GenericArrayVec::generic_from(*b"\x7eHDR:data");
+    ///
+    /// assert!(frame.generic_starts_with(b"\x7eHDR"));
+    /// assert!(!frame.generic_starts_with(b"HDR"));
+    /// ```
+    fn generic_starts_with(&self, needle: &[T]) -> bool
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: PartialEq,
+    {
+        self.as_slice().starts_with(needle)
+    }
+
+    /// Returns `true` if `needle` is a suffix of the vector's elements. An empty `needle` always
+    /// is.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let line = GenericArrayVec::generic_from(*b"OK\r\n");
+    ///
+    /// assert!(line.generic_ends_with(b"\r\n"));
+    /// assert!(!line.generic_ends_with(b"OK"));
+    /// ```
+    fn generic_ends_with(&self, needle: &[T]) -> bool
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: PartialEq,
+    {
+        self.as_slice().ends_with(needle)
+    }
+
+    /// Splits the elements of a full vector into two `GenericArray`s, the first `I` and the rest.
+    ///
+    /// The elements are moved, not cloned. `I` can't be more than `N`, which is checked at
+    /// compile time.
+    ///
+    /// **Errors** if `self` is not filled to capacity, returning it unchanged.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U2, U5};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let record: GenericArrayVec<u8, U5> = b"HDbody".iter().copied().take(5).collect();
+    /// let (header, body) = record.into_generic_arrays::<U2>().unwrap();
+    ///
+    /// assert_eq!(header.as_slice(), b"HD");
+    /// assert_eq!(body.as_slice(), b"bod");
+    /// ```
+    fn into_generic_arrays<I>(
+        self,
+    ) -> Result<(GenericArray<T, I>, GenericArray<T, Diff<N, I>>), Self>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        I: ArrayLength<T>,
+        N: Sub<I>,
+        Diff<N, I>: ArrayLength<T>,
     {
-        ArrayVec::from(ArrayvecStorage::from(arr.into()))
+        Ok(self.into_generic_array()?.split())
     }
 
-    /// Returns the inner `GenericArray`, if `self` is full to its capacity.
+    /// Sorts the vector and removes duplicate elements, leaving each distinct element exactly
+    /// once, in increasing order.
     ///
-    /// **Errors** if `self` is not filled to capacity.
+    /// This turns a small buffer into a set in place, without allocating. The sort is unstable, so
+    /// of several equal elements it's unspecified which is kept. The others are each dropped once.
+    /// Use [`dedup_all`](GenericArrayVecExt::dedup_all) instead to keep the original order.
     ///
     /// ```rust
-    /// use generic_arrayvec::typenum::U5;
     /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
     ///
-    /// let mut vec = GenericArrayVec::<i32, U5>::new();
+    /// let mut vec = GenericArrayVec::generic_from([3, 1, 2, 1, 3]);
+    /// vec.sort_dedup();
+    ///
+    /// assert_eq!(&vec[..], &[1, 2, 3]);
+    /// ```
+    fn sort_dedup(&mut self)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: Ord,
+    {
+        self.sort_unstable();
+
+        // Move each new distinct element down next to the last kept one. The duplicates end up
+        // past `kept`, where `truncate` drops them.
+        let mut kept = 0;
+        for i in 1..self.len() {
+            if self[i] != self[kept] {
+                kept += 1;
+                self.swap(kept, i);
+            }
+        }
+
+        if !self.is_empty() {
+            self.truncate(kept + 1);
+        }
+    }
+
+    /// Returns the live elements viewed as bytes, `len() * size_of::<T>()` of them.
+    ///
+    /// `T` can be any [`bytemuck::Pod`] type, such as the primitive numbers, which have no padding
+    /// and for which every bit pattern is a valid value. Requires the `bytemuck` feature.
+    ///
+    /// The unused capacity isn't included. Each element's bytes are in the platform's native byte
+    /// order, so the result differs between little- and big-endian targets; convert the elements
+    /// with e.g. [`u32::to_le`] first if the bytes leave the machine. The byte slice is only
+    /// aligned to 1, whatever `T`'s alignment.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::<u16, U4>::new();
+    /// vec.push(0x0102_u16.to_be());
+    /// vec.push(0x0304_u16.to_be());
+    ///
+    /// assert_eq!(vec.as_bytes(), &[1, 2, 3, 4]);
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    fn as_bytes(&self) -> &[u8]
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: bytemuck::Pod,
+    {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    /// Returns the live elements viewed as mutable bytes, `len() * size_of::<T>()` of them.
+    ///
+    /// The same caveats as for [`as_bytes`](GenericArrayVecExt::as_bytes) apply: the bytes are in
+    /// native byte order, and only cover the live elements. Requires the `bytemuck` feature.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec = GenericArrayVec::<u32, U4>::new();
     /// vec.push(0);
-    /// vec.extend(1..5);
+    /// vec.as_bytes_mut().copy_from_slice(&7_u32.to_ne_bytes());
     ///
-    /// let generic_array = vec.into_generic_array().unwrap();
+    /// assert_eq!(&vec[..], &[7]);
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        T: bytemuck::Pod,
+    {
+        bytemuck::cast_slice_mut(self.as_mut_slice())
+    }
+
+    /// Merges runs of adjacent elements in place, using `f` to decide whether and how to merge
+    /// each pair.
     ///
-    /// assert_eq!(&*generic_array, &[0, 1, 2, 3, 4][..]);
+    /// Walking from the front, `f` is given the last element kept so far and the next one. To
+    /// merge them, it updates the first as needed and returns `Ok(())`, taking ownership of the
+    /// second; to keep them separate, it returns the second as `Err`. Merged elements are dropped
+    /// by `f` like any value it owns, so each is dropped exactly once. This is an in-place version
+    /// of itertools' `coalesce`, handy for combining adjacent runs or intervals.
+    ///
+    /// If `f` panics, the vector is left holding the elements kept so far, and the rest are
+    /// dropped.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// // Runs of (value, count).
+    /// let mut runs = GenericArrayVec::generic_from([('a', 2), ('a', 1), ('b', 4), ('a', 3), ('a', 1)]);
+    /// runs.coalesce(|prev, next| {
+    ///     if prev.0 == next.0 {
+    ///         prev.1 += next.1;
+    ///         Ok(())
+    ///     } else {
+    ///         Err(next)
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(&runs[..], &[('a', 3), ('b', 4), ('a', 4)]);
     /// ```
-    fn into_generic_array(self) -> Result<GenericArray<T, N>, Self> {
-        Ok(self.into_inner()?.into_inner())
+    fn coalesce<F>(&mut self, mut f: F)
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&mut T, T) -> Result<(), T>,
+    {
+        for element in mem::take(self) {
+            let element = match self.last_mut() {
+                Some(prev) => match f(prev, element) {
+                    Ok(()) => continue,
+                    Err(element) => element,
+                },
+                None => element,
+            };
+            // There's room, since at most as many elements are pushed back as were taken.
+            self.push(element);
+        }
+    }
+
+    /// Returns an iterator over the elements, front to back.
+    ///
+    /// This is the same iterator as `iter()` and `&vec` give, but spelled out as a
+    /// [`slice::Iter`], so generic code that's only got this trait in scope can rely on it being
+    /// double-ended and exact-sized, for `.rev()`, `.enumerate()` and the like. Generic code still
+    /// needs the usual `where` bounds to name a `GenericArrayVec<T, N>`; to avoid them, take any
+    /// [`FixedSeq`] instead and call [`fixed_iter`](FixedSeq::fixed_iter).
+    ///
+    /// ```rust
+    /// use generic_arrayvec::arrayvec::Array;
+    /// use generic_arrayvec::{ArrayvecStorage, Capacity, GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// fn last_index_of<T, N>(vec: &GenericArrayVec<T, N>, value: &T) -> Option<usize>
+    /// where
+    ///     T: PartialEq,
+    ///     N: Capacity<T>,
+    ///     ArrayvecStorage<T, N>: Array<Item = T>,
+    /// {
+    ///     vec.generic_iter().rposition(|x| x == value)
+    /// }
+    ///
+    /// let vec = GenericArrayVec::generic_from([1, 2, 1, 3]);
+    /// assert_eq!(last_index_of(&vec, &1), Some(2));
+    /// ```
+    fn generic_iter(&self) -> slice::Iter<'_, T>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+    {
+        self.as_slice().iter()
+    }
+
+    /// Returns the capacity as a value of its `typenum` type `N`.
+    ///
+    /// `typenum` numbers are zero-sized, so this just materializes the unit value with
+    /// [`Default`]; it carries no information beyond the type, and costs nothing. It's handy for
+    /// passing the capacity along to APIs that take the number by value, such as `typenum`'s
+    /// operators.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{Unsigned, U2, U8};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// fn to_usize<M: Unsigned>(_: M) -> usize {
+    ///     M::USIZE
+    /// }
+    ///
+    /// let vec = GenericArrayVec::<i32, U8>::new();
+    /// let doubled = vec.capacity_typenum() * U2::default();
+    ///
+    /// assert_eq!(to_usize(doubled), 16);
+    /// ```
+    fn capacity_typenum(&self) -> N
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        N: Default,
+    {
+        N::default()
+    }
+
+    /// Splits the vector at its partition point, moving the elements after it into a new vector
+    /// of the same capacity.
+    ///
+    /// The vector is assumed to be partitioned by `pred`: all the elements it returns `true` for
+    /// come before all those it returns `false` for, as in a sorted vector split at a threshold.
+    /// The point is found by binary search with [`partition_point`](slice::partition_point),
+    /// after which `self` keeps the leading `true` group and the trailing `false` group is
+    /// returned, in order. If the vector isn't partitioned, the split point is unspecified, but
+    /// no elements are lost.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut low = GenericArrayVec::generic_from([1, 3, 5, 7, 9]);
+    /// let high = low.split_off_after(|&x| x < 6);
+    ///
+    /// assert_eq!(&low[..], &[1, 3, 5]);
+    /// assert_eq!(&high[..], &[7, 9]);
+    /// assert_eq!(high.capacity(), 5);
+    /// ```
+    fn split_off_after<F>(&mut self, pred: F) -> GenericArrayVec<T, N>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        F: FnMut(&T) -> bool,
+    {
+        let at = self.partition_point(pred);
+        self.drain(at..).collect()
+    }
+
+    /// Returns the elements as a slice of `GenericArray`s of length `C`, without copying, if the
+    /// length is a multiple of `C`.
+    ///
+    /// This is like [`slice::as_chunks`], but all-or-nothing: it returns `None` rather than
+    /// leaving a remainder. It suits processing a buffer in fixed-size blocks, such as for SIMD.
+    ///
+    /// **Panics** if `C` is zero.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U2, U3, U8};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let vec: GenericArrayVec<i32, U8> = (0..6).collect();
+    ///
+    /// let pairs = vec.as_generic_array_chunks::<U2>().unwrap();
+    /// assert_eq!(pairs.len(), 3);
+    /// assert_eq!(pairs[1].as_slice(), &[2, 3]);
+    ///
+    /// assert!(vec.as_generic_array_chunks::<U3>().is_some());
+    /// assert!(vec.as_generic_array_chunks::<U8>().is_none());
+    /// ```
+    fn as_generic_array_chunks<C>(&self) -> Option<&[GenericArray<T, C>]>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        C: ArrayLength<T>,
+    {
+        assert!(C::USIZE != 0, "chunk size must be nonzero");
+        if self.len() % C::USIZE != 0 {
+            return None;
+        }
+
+        // SAFETY: `GenericArray<T, C>` has the same layout as `[T; C]`, so `len / C` of them
+        // cover exactly the `len` initialized elements, and have the same alignment as `T`.
+        Some(unsafe {
+            slice::from_raw_parts(
+                self.as_ptr() as *const GenericArray<T, C>,
+                self.len() / C::USIZE,
+            )
+        })
+    }
+
+    /// Returns the elements as a mutable slice of `GenericArray`s of length `C`, without copying,
+    /// if the length is a multiple of `C`.
+    ///
+    /// The mutable counterpart to
+    /// [`as_generic_array_chunks`](GenericArrayVecExt::as_generic_array_chunks).
+    ///
+    /// **Panics** if `C` is zero.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U2, U8};
+    /// use generic_arrayvec::{GenericArrayVec, GenericArrayVecExt};
+    ///
+    /// let mut vec: GenericArrayVec<i32, U8> = (0..4).collect();
+    /// for pair in vec.as_generic_array_chunks_mut::<U2>().unwrap() {
+    ///     pair.swap(0, 1);
+    /// }
+    ///
+    /// assert_eq!(&vec[..], &[1, 0, 3, 2]);
+    /// ```
+    fn as_generic_array_chunks_mut<C>(&mut self) -> Option<&mut [GenericArray<T, C>]>
+    where
+        ArrayvecStorage<T, N>: Array<Item = T>,
+        C: ArrayLength<T>,
+    {
+        assert!(C::USIZE != 0, "chunk size must be nonzero");
+        if self.len() % C::USIZE != 0 {
+            return None;
+        }
+
+        // SAFETY: as in `as_generic_array_chunks`, and the chunks borrow `self` mutably.
+        Some(unsafe {
+            slice::from_raw_parts_mut(
+                self.as_mut_ptr() as *mut GenericArray<T, C>,
+                self.len() / C::USIZE,
+            )
+        })
+    }
+}
+
+/// The fallible counterpart to [`Extend`], for collections with a fixed capacity.
+pub trait TryExtend<T> {
+    fn try_extend<I>(&mut self, iter: I) -> Result<(), CapacityError<T>>
+    where
+        I: IntoIterator<Item = T>;
+}
+
+/// Implemented for every `ArrayVec`, not just [`GenericArrayVec`], so that [`GVec`]'s impl can
+/// delegate to it with only a [`GVecCapacity`] bound.
+impl<A> TryExtend<A::Item> for ArrayVec<A>
+where
+    A: Array,
+{
+    /// Appends the elements of `iter` to the vector, in order.
+    ///
+    /// [`Extend::extend`] silently drops the elements that don't fit; this reports the overflow
+    /// instead.
+    ///
+    /// **Errors** at the first element that doesn't fit, returning that element. The elements
+    /// pushed before it stay in the vector, and no more are taken from `iter`. To get at the ones
+    /// left over, pass `iter.by_ref()` and keep using `iter` afterwards.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U3;
+    /// use generic_arrayvec::{GenericArrayVec, TryExtend};
+    ///
+    /// let mut vec = GenericArrayVec::<i32, U3>::new();
+    /// vec.try_extend(0..2).unwrap();
+    ///
+    /// let mut iter = 2..6;
+    /// let err = vec.try_extend(iter.by_ref()).unwrap_err();
+    /// assert_eq!(err.element(), 3);
+    /// assert_eq!(&vec[..], &[0, 1, 2]);
+    /// assert!(iter.eq(4..6));
+    /// ```
+    fn try_extend<I>(&mut self, iter: I) -> Result<(), CapacityError<A::Item>>
+    where
+        I: IntoIterator<Item = A::Item>,
+    {
+        for element in iter {
+            self.try_push(element)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Calling `check()` fails compilation unless `N::USIZE == M`.
+struct SameCapacity<N, const M: usize>(PhantomData<N>);
+
+impl<N, const M: usize> SameCapacity<N, M>
+where
+    N: Unsigned,
+{
+    const ASSERT: () = assert_capacity_eq::<N>(M);
+
+    fn check() {
+        Self::ASSERT
+    }
+}
+
+/// Calling `check()` fails compilation unless `T` and `U` have the same size.
+struct SameSize<T, U>(PhantomData<(T, U)>);
+
+impl<T, U> SameSize<T, U> {
+    const ASSERT: () = assert!(
+        size_of::<T>() == size_of::<U>(),
+        "element sizes don't match"
+    );
+
+    fn check() {
+        Self::ASSERT
     }
 }
 
@@ -224,11 +3078,46 @@ where
 
     N::ArrayType: Copy,
 {
+    const CAPACITY: usize;
+
     fn generic_from(string: &str) -> Result<GenericArrayString<N>, CapacityError<&str>>;
 
     fn generic_from_byte_string<A>(byte_string: &A) -> Result<GenericArrayString<N>, Utf8Error>
     where
         A: Into<GenericArray<u8, N>> + AsRef<[u8]>;
+
+    fn generic_concat<M>(
+        &self,
+        other: &GenericArrayString<M>,
+    ) -> GenericArrayString<typenum::Sum<N, M>>
+    where
+        N: Add<M>,
+        M: Capacity<u8>,
+        ArrayvecStorage<u8, M>: Array<Item = u8>,
+        M::ArrayType: Copy,
+        typenum::Sum<N, M>: Capacity<u8>,
+        ArrayvecStorage<u8, typenum::Sum<N, M>>: Array<Item = u8>,
+        <typenum::Sum<N, M> as ArrayLength<u8>>::ArrayType: Copy;
+
+    fn try_concat_into<O>(&self, other: &str) -> Result<GenericArrayString<O>, CapacityError>
+    where
+        O: Capacity<u8>,
+        ArrayvecStorage<u8, O>: Array<Item = u8>,
+        O::ArrayType: Copy;
+
+    fn generic_from_truncating(string: &str) -> GenericArrayString<N>;
+
+    fn generic_format(args: fmt::Arguments<'_>) -> Result<GenericArrayString<N>, CapacityError>;
+
+    fn generic_truncate_chars(&mut self, max_bytes: usize);
+
+    fn generic_from_utf8_iter<I>(iter: I) -> Result<GenericArrayString<N>, Utf8OrCapacityError>
+    where
+        I: IntoIterator<Item = u8>;
+
+    fn char_boundaries(&self) -> CharBoundaries<'_>;
+
+    fn fits_formatted(args: fmt::Arguments<'_>) -> bool;
 }
 
 impl<N> GenericArrayStringExt<N> for GenericArrayString<N>
@@ -238,12 +3127,32 @@ where
 
     N::ArrayType: Copy,
 {
+    /// The capacity of the string in bytes, `N`, as a `usize`.
+    ///
+    /// Like [`capacity`], this is usable in `const` contexts where `N` is a concrete type. Trait
+    /// methods can't be `const fn`, so for a function rather than a constant, use [`capacity`].
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U16;
+    /// use generic_arrayvec::{GenericArrayString, GenericArrayStringExt};
+    ///
+    /// let buf = [0u8; GenericArrayString::<U16>::CAPACITY];
+    ///
+    /// assert_eq!(buf.len(), 16);
+    /// ```
+    const CAPACITY: usize = N::USIZE;
+
     /// Creates a `GenericArrayString` from a `str`.
     ///
     /// Capacity is inferred from the type parameter.
     ///
     /// **Errors** if the capacity is not large enough to fit the string.
     ///
+    /// This crate can't implement `TryFrom<&str>` for `GenericArrayString`, since both the trait
+    /// and arrayvec's `ArrayString` are foreign to it, and arrayvec doesn't implement it either.
+    /// [`str::parse`] works through arrayvec's `FromStr` impl, though, where a trait bound is
+    /// needed.
+    ///
     /// ```rust
     /// use generic_arrayvec::typenum::U10;
     /// use generic_arrayvec::{GenericArrayString, GenericArrayStringExt};
@@ -296,6 +3205,274 @@ where
             byte_string.as_ref(),
         )))
     }
+
+    /// Returns a new string holding `self` followed by `other`, with a capacity of `N + M`.
+    ///
+    /// Since the capacity is the sum of both inputs' capacities, the result always fits.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U3, U5};
+    /// use generic_arrayvec::{GenericArrayString, GenericArrayStringExt};
+    ///
+    /// let a = GenericArrayString::<U3>::generic_from("foo").unwrap();
+    /// let b = GenericArrayString::<U5>::generic_from("bar").unwrap();
+    /// let joined = a.generic_concat(&b);
+    ///
+    /// assert_eq!(&joined[..], "foobar");
+    /// assert_eq!(joined.capacity(), 8);
+    /// ```
+    fn generic_concat<M>(
+        &self,
+        other: &GenericArrayString<M>,
+    ) -> GenericArrayString<typenum::Sum<N, M>>
+    where
+        N: Add<M>,
+        M: Capacity<u8>,
+        ArrayvecStorage<u8, M>: Array<Item = u8>,
+        M::ArrayType: Copy,
+        typenum::Sum<N, M>: Capacity<u8>,
+        ArrayvecStorage<u8, typenum::Sum<N, M>>: Array<Item = u8>,
+        <typenum::Sum<N, M> as ArrayLength<u8>>::ArrayType: Copy,
+    {
+        self.try_concat_into(other)
+            .expect("the sum of the capacities fits both strings")
+    }
+
+    /// Returns a new string, with capacity `O`, holding `self` followed by `other`.
+    ///
+    /// **Errors** if the combined length exceeds `O`.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U4, U8};
+    /// use generic_arrayvec::{GenericArrayString, GenericArrayStringExt};
+    ///
+    /// let a = GenericArrayString::<U4>::generic_from("foo").unwrap();
+    ///
+    /// let joined = a.try_concat_into::<U8>("bar").unwrap();
+    /// assert_eq!(&joined[..], "foobar");
+    ///
+    /// assert!(a.try_concat_into::<U4>("bar").is_err());
+    /// ```
+    fn try_concat_into<O>(&self, other: &str) -> Result<GenericArrayString<O>, CapacityError>
+    where
+        O: Capacity<u8>,
+        ArrayvecStorage<u8, O>: Array<Item = u8>,
+        O::ArrayType: Copy,
+    {
+        let mut string = GenericArrayString::new();
+        string.try_push_str(self).map_err(CapacityError::simplify)?;
+        string
+            .try_push_str(other)
+            .map_err(CapacityError::simplify)?;
+
+        Ok(string)
+    }
+
+    /// Creates a `GenericArrayString` from as much of a `str` as fits.
+    ///
+    /// Only whole characters are copied, so if the string doesn't fit, the result may be shorter
+    /// than the capacity rather than ending in part of a multibyte character. Never errors.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U4;
+    /// use generic_arrayvec::{GenericArrayString, GenericArrayStringExt};
+    ///
+    /// let string = GenericArrayString::<U4>::generic_from_truncating("hello");
+    /// assert_eq!(&string[..], "hell");
+    ///
+    /// // 'é' is two bytes long, and doesn't fit in the one byte left after "abc".
+    /// let string = GenericArrayString::<U4>::generic_from_truncating("abcé");
+    /// assert_eq!(&string[..], "abc");
+    /// ```
+    fn generic_from_truncating(string: &str) -> GenericArrayString<N> {
+        let mut end = string.len().min(N::USIZE);
+        while !string.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        ArrayString::from(&string[..end]).expect("the prefix fits within the capacity")
+    }
+
+    /// Creates a `GenericArrayString` holding the formatted `args`, without allocating.
+    ///
+    /// The [`gformat!`] macro is a more convenient way to call this.
+    ///
+    /// **Errors** if the formatted string doesn't fit; nothing is truncated. This also errors if
+    /// one of the values' formatting impls returns an error.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U4, U8};
+    /// use generic_arrayvec::{GenericArrayString, GenericArrayStringExt};
+    ///
+    /// let string = GenericArrayString::<U8>::generic_format(format_args!("{}-{}", 12, 34)).unwrap();
+    /// assert_eq!(&string[..], "12-34");
+    ///
+    /// assert!(GenericArrayString::<U4>::generic_format(format_args!("{}-{}", 12, 34)).is_err());
+    /// ```
+    fn generic_format(args: fmt::Arguments<'_>) -> Result<GenericArrayString<N>, CapacityError> {
+        let mut string = GenericArrayString::new();
+        fmt::Write::write_fmt(&mut string, args).map_err(|_| CapacityError::new(()))?;
+
+        Ok(string)
+    }
+
+    /// Shortens the string to at most `max_bytes` bytes, cutting at the nearest character
+    /// boundary at or before it.
+    ///
+    /// Unlike [`truncate`](ArrayString::truncate), this doesn't panic if `max_bytes` falls inside
+    /// a multibyte character; that character is removed entirely instead. Has no effect if
+    /// `max_bytes` is greater than the string's current length.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U8;
+    /// use generic_arrayvec::{GenericArrayString, GenericArrayStringExt};
+    ///
+    /// let mut string = GenericArrayString::<U8>::generic_from("añb").unwrap();
+    /// string.generic_truncate_chars(2);
+    ///
+    /// assert_eq!(&string[..], "a");
+    /// ```
+    fn generic_truncate_chars(&mut self, max_bytes: usize) {
+        let mut end = max_bytes.min(self.len());
+        while !self.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        self.truncate(end);
+    }
+
+    /// Creates a `GenericArrayString` from a stream of bytes, validating them as UTF-8 as they
+    /// come in.
+    ///
+    /// No buffer is needed beyond the string itself: the bytes of each character are validated
+    /// once it's complete, however they're split up, and then appended.
+    ///
+    /// **Errors** with whichever comes first of:
+    ///
+    /// - [`Utf8OrCapacityError::InvalidUtf8`] if the bytes aren't valid UTF-8, including if they
+    ///   end partway through a character, or
+    /// - [`Utf8OrCapacityError::Capacity`] if the string doesn't fit within the capacity.
+    ///
+    /// The iterator isn't consumed past the byte where the error was found.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::{U4, U8};
+    /// use generic_arrayvec::{GenericArrayString, GenericArrayStringExt, Utf8OrCapacityError};
+    ///
+    /// let bytes = "h€y".bytes();
+    /// let string = GenericArrayString::<U8>::generic_from_utf8_iter(bytes).unwrap();
+    /// assert_eq!(&string[..], "h€y");
+    ///
+    /// assert_eq!(
+    ///     GenericArrayString::<U8>::generic_from_utf8_iter(vec![b'h', 0xe2, 0x82]),
+    ///     Err(Utf8OrCapacityError::InvalidUtf8 { valid_up_to: 1 }),
+    /// );
+    /// assert_eq!(
+    ///     GenericArrayString::<U4>::generic_from_utf8_iter("h€y".bytes()),
+    ///     Err(Utf8OrCapacityError::Capacity),
+    /// );
+    /// ```
+    fn generic_from_utf8_iter<I>(iter: I) -> Result<GenericArrayString<N>, Utf8OrCapacityError>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let mut string = GenericArrayString::new();
+        // The bytes of the character currently being read, and how many it has in all.
+        let mut pending = [0; 4];
+        let mut pending_len = 0;
+        let mut width = 0;
+
+        for byte in iter {
+            let invalid = Utf8OrCapacityError::InvalidUtf8 {
+                valid_up_to: string.len(),
+            };
+            if pending_len == 0 {
+                width = match byte {
+                    0x00..=0x7f => 1,
+                    0xc2..=0xdf => 2,
+                    0xe0..=0xef => 3,
+                    0xf0..=0xf4 => 4,
+                    _ => return Err(invalid),
+                };
+            } else if byte & 0xc0 != 0x80 {
+                return Err(invalid);
+            }
+
+            pending[pending_len] = byte;
+            pending_len += 1;
+            if pending_len == width {
+                // This also rejects the overlong encodings, surrogates and values past U+10FFFF
+                // that the checks above let through.
+                let c = core::str::from_utf8(&pending[..width]).map_err(|_| invalid)?;
+                string
+                    .try_push_str(c)
+                    .map_err(|_| Utf8OrCapacityError::Capacity)?;
+                pending_len = 0;
+            }
+        }
+
+        if pending_len != 0 {
+            return Err(Utf8OrCapacityError::InvalidUtf8 {
+                valid_up_to: string.len(),
+            });
+        }
+
+        Ok(string)
+    }
+
+    /// Returns an iterator over the byte offsets at which each char of the string starts, in
+    /// increasing order.
+    ///
+    /// These are exactly the offsets for which [`str::is_char_boundary`] is true, except for the
+    /// length of the string, which isn't yielded. That makes it easy to move a cursor by whole
+    /// chars. The iterator is double-ended, so [`rev`](Iterator::rev) walks backwards.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U8;
+    /// use generic_arrayvec::{GenericArrayString, GenericArrayStringExt};
+    ///
+    /// let string = GenericArrayString::<U8>::from("a€b").unwrap();
+    ///
+    /// assert!(string.char_boundaries().eq([0, 1, 4].iter().copied()));
+    /// assert_eq!(string.char_boundaries().rev().nth(1), Some(1));
+    /// ```
+    fn char_boundaries(&self) -> CharBoundaries<'_> {
+        CharBoundaries::new(self)
+    }
+
+    /// Returns whether the formatted `args` would fit in a `GenericArrayString<N>`, without
+    /// writing them anywhere.
+    ///
+    /// This formats `args` into a sink that only counts the bytes, stopping as soon as there are
+    /// more than `N`. Use it to check before a `write!` that could otherwise fail partway,
+    /// leaving a partial write behind. The arguments are formatted again when you do write them,
+    /// so this assumes their formatting is deterministic. Returns `false` if one of the values'
+    /// formatting impls returns an error.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U5;
+    /// use generic_arrayvec::{GenericArrayString, GenericArrayStringExt};
+    ///
+    /// assert!(GenericArrayString::<U5>::fits_formatted(format_args!("{}-{}", 12, 34)));
+    /// assert!(!GenericArrayString::<U5>::fits_formatted(format_args!("{}-{}", 12, 345)));
+    /// ```
+    fn fits_formatted(args: fmt::Arguments<'_>) -> bool {
+        struct Counter {
+            remaining: usize,
+        }
+
+        impl fmt::Write for Counter {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.remaining = self.remaining.checked_sub(s.len()).ok_or(fmt::Error)?;
+                Ok(())
+            }
+        }
+
+        let mut counter = Counter {
+            remaining: N::USIZE,
+        };
+        fmt::Write::write_fmt(&mut counter, args).is_ok()
+    }
 }
 
 mod private {
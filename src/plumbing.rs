@@ -5,6 +5,18 @@ use core::marker::PhantomData;
 use generic_array::typenum::{False, IsLess, True, U0, U1, U2, U256, U4294967296, U65536};
 use generic_array::{ArrayLength, GenericArray};
 
+/// The type behind [`ArrayvecStorage`](crate::ArrayvecStorage), with the index type `I` that
+/// stores the vector's length left as a parameter.
+///
+/// [`Array`] is implemented for `I` being `()`, `bool`, `u8`, `u16`, `u32` or `usize`, each
+/// restricted to the capacities that type can count up to. `ArrayvecStorage` picks the smallest
+/// of these for `N`, but you can name a wider one directly, e.g.
+/// `ArrayVec<ArrayvecStorageRaw<T, N, usize>>`.
+///
+/// Other index types, such as `NonZeroU8`, can't be supported, by this crate or downstream.
+/// arrayvec requires `Array::Index` to implement its own `Index` trait, which isn't nameable
+/// outside arrayvec and only covers the types above. And a downstream crate can't implement
+/// `Array` for this type anyway, since both are foreign to it, even with a local `I`.
 #[derive(Debug, Clone)]
 #[repr(transparent)]
 pub struct ArrayvecStorageRaw<T, N, I>(pub GenericArray<T, N>, PhantomData<I>)
@@ -160,8 +172,57 @@ impl IsTrue for True {
     fn _sealed(_: Sealed) {}
 }
 
+/// Implemented by each of the types a vector's length can be stored as, giving the largest length
+/// it can represent.
+///
+/// This is also the largest capacity that uses the type. For a given capacity `N`, the type picked
+/// is [`IndexForCapacity<N>`]:
+///
+/// ```rust
+/// use generic_arrayvec::plumbing::{IndexForCapacity, MaxIndex};
+/// use generic_arrayvec::typenum::{U1, U255, U256};
+///
+/// assert_eq!(<IndexForCapacity<U1> as MaxIndex>::MAX_INDEX, 1);
+/// assert_eq!(<IndexForCapacity<U255> as MaxIndex>::MAX_INDEX, 255);
+/// assert_eq!(<IndexForCapacity<U256> as MaxIndex>::MAX_INDEX, 65535);
+/// ```
+pub trait MaxIndex {
+    const MAX_INDEX: usize;
+    fn _sealed(_: Sealed);
+}
+
+impl MaxIndex for () {
+    const MAX_INDEX: usize = 0;
+    fn _sealed(_: Sealed) {}
+}
+
+impl MaxIndex for bool {
+    const MAX_INDEX: usize = 1;
+    fn _sealed(_: Sealed) {}
+}
+
+impl MaxIndex for u8 {
+    const MAX_INDEX: usize = u8::MAX as usize;
+    fn _sealed(_: Sealed) {}
+}
+
+impl MaxIndex for u16 {
+    const MAX_INDEX: usize = u16::MAX as usize;
+    fn _sealed(_: Sealed) {}
+}
+
+impl MaxIndex for u32 {
+    const MAX_INDEX: usize = u32::MAX as usize;
+    fn _sealed(_: Sealed) {}
+}
+
+impl MaxIndex for usize {
+    const MAX_INDEX: usize = usize::MAX;
+    fn _sealed(_: Sealed) {}
+}
+
 pub trait PickIndex {
-    type Output: Copy;
+    type Output: Copy + MaxIndex;
     fn _sealed(_: Sealed);
 }
 
@@ -0,0 +1,245 @@
+use crate::iter::FixedChunks;
+use crate::{ArrayvecStorage, Capacity, GVec, GVecCapacity, GenericArrayString, GenericArrayVec};
+use arrayvec::{Array, IntoIter};
+use core::slice;
+
+/// A trait for iterating over the fixed-capacity containers in this crate without caring which
+/// one you have.
+///
+/// It's implemented for [`GenericArrayVec`] and [`GVec`], and for [`GenericArrayString`], which
+/// iterates over its bytes. Since mutating a string's bytes could break UTF-8 validity, mutable
+/// iteration is split out into [`FixedSeqMut`], which strings don't implement.
+///
+/// The methods are prefixed with `fixed_` so they don't clash with the containers' own `iter()`
+/// and `into_iter()`.
+///
+/// ```rust
+/// use generic_arrayvec::typenum::U4;
+/// use generic_arrayvec::{FixedSeq, GenericArrayString, GenericArrayStringExt, GenericArrayVec};
+///
+/// fn sum<S>(seq: &S) -> u32
+/// where
+///     S: FixedSeq<Item = u8>,
+/// {
+///     seq.fixed_iter().map(|&x| u32::from(x)).sum()
+/// }
+///
+/// let vec: GenericArrayVec<u8, U4> = (1..=3).collect();
+/// let string = GenericArrayString::<U4>::generic_from("ab").unwrap();
+///
+/// assert_eq!(sum(&vec), 6);
+/// assert_eq!(sum(&string), 97 + 98);
+/// ```
+pub trait FixedSeq {
+    type Item;
+    type IntoIter: Iterator<Item = Self::Item>;
+
+    /// Returns an iterator over references to the elements, front to back.
+    fn fixed_iter(&self) -> slice::Iter<'_, Self::Item>;
+
+    /// Returns an iterator that moves the elements out, front to back.
+    fn fixed_into_iter(self) -> Self::IntoIter;
+}
+
+/// A [`FixedSeq`] whose elements can be mutated in place.
+pub trait FixedSeqMut: FixedSeq {
+    /// Returns an iterator over mutable references to the elements, front to back.
+    fn fixed_iter_mut(&mut self) -> slice::IterMut<'_, Self::Item>;
+}
+
+impl<T, N> FixedSeq for GenericArrayVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    type Item = T;
+    type IntoIter = IntoIter<ArrayvecStorage<T, N>>;
+
+    fn fixed_iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    fn fixed_into_iter(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+impl<T, N> FixedSeqMut for GenericArrayVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    fn fixed_iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T, N> FixedSeq for GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    type Item = T;
+    type IntoIter = IntoIter<N::Storage>;
+
+    fn fixed_iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    fn fixed_into_iter(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+impl<T, N> FixedSeqMut for GVec<T, N>
+where
+    N: GVecCapacity<T>,
+{
+    fn fixed_iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<N> FixedSeq for GenericArrayString<N>
+where
+    N: Capacity<u8>,
+    ArrayvecStorage<u8, N>: Array<Item = u8>,
+
+    N::ArrayType: Copy,
+{
+    type Item = u8;
+    type IntoIter = IntoIter<ArrayvecStorage<u8, N>>;
+
+    fn fixed_iter(&self) -> slice::Iter<'_, u8> {
+        self.as_bytes().iter()
+    }
+
+    fn fixed_into_iter(self) -> Self::IntoIter {
+        let mut bytes = GenericArrayVec::new();
+        bytes
+            .try_extend_from_slice(self.as_bytes())
+            .expect("a string's bytes fit in a vector of the same capacity");
+
+        bytes.into_iter()
+    }
+}
+
+/// An object-safe view of a [`GenericArrayVec`], with its capacity erased.
+///
+/// This lets vectors of the same element type but different capacities be used through the same
+/// trait object, e.g. `&mut dyn DynFixedVec<T>` or `Box<dyn DynFixedVec<T>>`.
+///
+/// ```rust
+/// use generic_arrayvec::typenum::{U2, U8};
+/// use generic_arrayvec::{DynFixedVec, GenericArrayVec};
+///
+/// let small: GenericArrayVec<i32, U2> = (0..2).collect();
+/// let large: GenericArrayVec<i32, U8> = (0..5).collect();
+/// let vecs: [&dyn DynFixedVec<i32>; 2] = [&small, &large];
+///
+/// let capacities: Vec<_> = vecs.iter().map(|vec| vec.capacity()).collect();
+/// assert_eq!(capacities, [2, 8]);
+/// assert_eq!(vecs[1].as_slice(), &[0, 1, 2, 3, 4]);
+/// ```
+pub trait DynFixedVec<T> {
+    /// Returns a slice containing all elements of the vector.
+    fn as_slice(&self) -> &[T];
+
+    /// Returns a mutable slice containing all elements of the vector.
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    /// Returns the number of elements in the vector.
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the capacity of the vector.
+    fn capacity(&self) -> usize;
+}
+
+impl<T, N> DynFixedVec<T> for GenericArrayVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    fn as_slice(&self) -> &[T] {
+        GenericArrayVec::as_slice(self)
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        GenericArrayVec::as_mut_slice(self)
+    }
+
+    fn capacity(&self) -> usize {
+        GenericArrayVec::capacity(self)
+    }
+}
+
+/// An extension trait for collecting an iterator into a [`GenericArrayVec`] without risk of
+/// overflow.
+pub trait CollectLossy: Iterator {
+    /// Collects the first `N` elements into a vector, and drops the rest.
+    ///
+    /// The iterator is always run to completion. Returns the vector along with the number of
+    /// elements that didn't fit and were dropped. Never panics.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U3;
+    /// use generic_arrayvec::CollectLossy;
+    ///
+    /// let (vec, dropped) = (0..10).collect_generic_lossy::<U3>();
+    ///
+    /// assert_eq!(&vec[..], &[0, 1, 2]);
+    /// assert_eq!(dropped, 7);
+    /// ```
+    fn collect_generic_lossy<N>(mut self) -> (GenericArrayVec<Self::Item, N>, usize)
+    where
+        Self: Sized,
+        N: Capacity<Self::Item>,
+        ArrayvecStorage<Self::Item, N>: Array<Item = Self::Item>,
+    {
+        let mut vec = GenericArrayVec::new();
+        vec.extend(self.by_ref().take(N::USIZE));
+
+        (vec, self.count())
+    }
+}
+
+impl<I> CollectLossy for I where I: Iterator {}
+
+/// An extension trait for batching the elements of an iterator into [`GenericArrayVec`]s.
+pub trait IterFixedExt: Iterator {
+    /// Returns an iterator over the elements in batches, each a vector of capacity `N`.
+    ///
+    /// Every batch is full except possibly the last, which holds the remaining elements. No more
+    /// elements are pulled from `self` than are needed for the batch being built, and once it
+    /// runs out, it isn't polled again.
+    ///
+    /// **Panics** if `N` is zero.
+    ///
+    /// ```rust
+    /// use generic_arrayvec::typenum::U3;
+    /// use generic_arrayvec::IterFixedExt;
+    ///
+    /// let mut chunks = (0..7).fixed_chunks::<U3>();
+    ///
+    /// assert_eq!(&chunks.next().unwrap()[..], &[0, 1, 2]);
+    /// assert_eq!(&chunks.next().unwrap()[..], &[3, 4, 5]);
+    /// assert_eq!(&chunks.next().unwrap()[..], &[6]);
+    /// assert!(chunks.next().is_none());
+    /// ```
+    fn fixed_chunks<N>(self) -> FixedChunks<Self, N>
+    where
+        Self: Sized,
+        N: Capacity<Self::Item>,
+        ArrayvecStorage<Self::Item, N>: Array<Item = Self::Item>,
+    {
+        FixedChunks::new(self)
+    }
+}
+
+impl<I> IterFixedExt for I where I: Iterator {}
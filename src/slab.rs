@@ -0,0 +1,383 @@
+use crate::bitset::{BitCapacity, GenericBitSet, Ones};
+use crate::Capacity;
+use arrayvec::CapacityError;
+use core::fmt::{self, Debug};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use generic_array::GenericArray;
+
+/// A fixed-capacity slab: `N` slots, each either holding an element or vacant, with elements
+/// addressed by their slot index.
+///
+/// Unlike a vector, taking an element out leaves a hole, so the indices of the other elements
+/// don't change. That makes it suitable for object pools, where the index serves as a handle.
+/// [`insert`] fills the lowest vacant slot, so freed slots are reused; [`put`] fills a given
+/// one. Which slots are occupied is tracked in a [`GenericBitSet`], and [`iter`] visits only
+/// those.
+///
+/// The capacity needs both the usual `N: Capacity<T>` bound and `N: BitCapacity` for the
+/// occupancy set. Both hold for every `typenum` number.
+///
+/// [`insert`]: GenericSlab::insert
+/// [`put`]: GenericSlab::put
+/// [`iter`]: GenericSlab::iter
+///
+/// ```rust
+/// use generic_arrayvec::slab::GenericSlab;
+/// use generic_arrayvec::typenum::U4;
+///
+/// let mut slab = GenericSlab::<&str, U4>::new();
+/// let a = slab.insert("a").unwrap();
+/// let b = slab.insert("b").unwrap();
+/// let c = slab.insert("c").unwrap();
+///
+/// assert_eq!(slab.take(b), Some("b"));
+/// assert_eq!(slab.take(b), None);
+/// assert_eq!(slab.get(c), Some(&"c"));
+///
+/// // The freed slot is reused.
+/// assert_eq!(slab.insert("d"), Ok(b));
+/// assert!(slab.iter().eq([(a, &"a"), (b, &"d"), (c, &"c")].iter().copied()));
+/// ```
+pub struct GenericSlab<T, N>
+where
+    N: Capacity<T> + BitCapacity,
+{
+    buf: MaybeUninit<GenericArray<T, N>>,
+    occupied: GenericBitSet<N>,
+    len: usize,
+}
+
+impl<T, N> GenericSlab<T, N>
+where
+    N: Capacity<T> + BitCapacity,
+{
+    /// Creates a new slab with every slot vacant.
+    pub fn new() -> Self {
+        GenericSlab {
+            buf: MaybeUninit::uninit(),
+            occupied: GenericBitSet::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of occupied slots.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if every slot is vacant.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if every slot is occupied.
+    pub fn is_full(&self) -> bool {
+        self.len == N::USIZE
+    }
+
+    /// Returns the number of slots, which is `N`.
+    pub fn capacity(&self) -> usize {
+        N::USIZE
+    }
+
+    /// Returns `true` if slot `index` is occupied, or `false` if it's vacant or out of bounds.
+    pub fn contains(&self, index: usize) -> bool {
+        index < N::USIZE && self.occupied.get(index)
+    }
+
+    /// Stores an element in the lowest vacant slot, and returns that slot's index.
+    ///
+    /// **Errors** if every slot is occupied, returning the element.
+    pub fn insert(&mut self, element: T) -> Result<usize, CapacityError<T>> {
+        match self.first_vacant() {
+            Some(index) => {
+                self.put(index, element);
+                Ok(index)
+            }
+            None => Err(CapacityError::new(element)),
+        }
+    }
+
+    /// Stores an element in slot `index`, returning the element it previously held, if any.
+    ///
+    /// **Panics** if `index` is out of bounds.
+    pub fn put(&mut self, index: usize, element: T) -> Option<T> {
+        assert!(
+            index < N::USIZE,
+            "slot index {} is out of range for a slab of {} slots",
+            index,
+            N::USIZE
+        );
+        let old = self.take(index);
+
+        // SAFETY: the slot is in bounds, and vacant now that any previous element was taken.
+        unsafe { self.slot_ptr(index).write(element) };
+        self.occupied.set(index);
+        self.len += 1;
+
+        old
+    }
+
+    /// Moves the element out of slot `index`, leaving it vacant, or returns `None` if it's
+    /// already vacant or out of bounds.
+    pub fn take(&mut self, index: usize) -> Option<T> {
+        if !self.contains(index) {
+            return None;
+        }
+
+        self.occupied.clear(index);
+        self.len -= 1;
+        // SAFETY: the slot was occupied, and is no longer marked so.
+        Some(unsafe { self.slot_ptr(index).read() })
+    }
+
+    /// Returns a reference to the element in slot `index`, or `None` if it's vacant or out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if self.contains(index) {
+            // SAFETY: the slot is occupied.
+            Some(unsafe { &*self.slot_ptr_const(index) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element in slot `index`, or `None` if it's vacant or
+    /// out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if self.contains(index) {
+            // SAFETY: the slot is occupied.
+            Some(unsafe { &mut *self.slot_ptr(index) })
+        } else {
+            None
+        }
+    }
+
+    /// Drops every element, leaving all slots vacant.
+    pub fn clear(&mut self) {
+        for index in 0..N::USIZE {
+            drop(self.take(index));
+        }
+    }
+
+    /// Returns an iterator over the occupied slots, as pairs of the index and a reference to the
+    /// element, in increasing order of index.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            ones: self.occupied.iter_ones(),
+            base: self.slot_ptr_const(0),
+            remaining: self.len,
+            _elements: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the occupied slots, as pairs of the index and a mutable reference
+    /// to the element, in increasing order of index.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            base: self.slot_ptr(0),
+            ones: self.occupied.iter_ones(),
+            remaining: self.len,
+            _elements: PhantomData,
+        }
+    }
+
+    fn first_vacant(&self) -> Option<usize> {
+        let (byte_index, byte) = self
+            .occupied
+            .as_bytes()
+            .iter()
+            .enumerate()
+            .find(|(_, &byte)| byte != u8::MAX)?;
+        let index = byte_index * 8 + byte.trailing_ones() as usize;
+
+        // The bits past `N` in the last byte are always clear, so this can land on one of them.
+        if index < N::USIZE {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    fn slot_ptr_const(&self, index: usize) -> *const T {
+        (self.buf.as_ptr() as *const T).wrapping_add(index)
+    }
+
+    fn slot_ptr(&mut self, index: usize) -> *mut T {
+        (self.buf.as_mut_ptr() as *mut T).wrapping_add(index)
+    }
+}
+
+impl<T, N> Drop for GenericSlab<T, N>
+where
+    N: Capacity<T> + BitCapacity,
+{
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, N> Default for GenericSlab<T, N>
+where
+    N: Capacity<T> + BitCapacity,
+{
+    fn default() -> Self {
+        GenericSlab::new()
+    }
+}
+
+impl<T, N> Clone for GenericSlab<T, N>
+where
+    T: Clone,
+    N: Capacity<T> + BitCapacity,
+{
+    fn clone(&self) -> Self {
+        let mut slab = GenericSlab::new();
+        for (index, element) in self {
+            slab.put(index, element.clone());
+        }
+
+        slab
+    }
+}
+
+impl<T, N> Debug for GenericSlab<T, N>
+where
+    T: Debug,
+    N: Capacity<T> + BitCapacity,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self).finish()
+    }
+}
+
+impl<'a, T, N> IntoIterator for &'a GenericSlab<T, N>
+where
+    N: Capacity<T> + BitCapacity,
+{
+    type Item = (usize, &'a T);
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, N> IntoIterator for &'a mut GenericSlab<T, N>
+where
+    N: Capacity<T> + BitCapacity,
+{
+    type Item = (usize, &'a mut T);
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// An iterator over the occupied slots of a [`GenericSlab`], as pairs of the index and a
+/// reference to the element.
+///
+/// Returned by [`GenericSlab::iter`].
+pub struct Iter<'a, T> {
+    ones: Ones<'a>,
+    base: *const T,
+    remaining: usize,
+    _elements: PhantomData<&'a T>,
+}
+
+// SAFETY: `Iter` only hands out `&'a T`s, so it can be sent or shared across threads exactly
+// when `&'a T` can, which is when `T: Sync`.
+unsafe impl<'a, T: Sync> Send for Iter<'a, T> {}
+
+// SAFETY: as for `Send`; a shared `Iter` gives out nothing more than `&'a T`s.
+unsafe impl<'a, T: Sync> Sync for Iter<'a, T> {}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            ones: self.ones.clone(),
+            base: self.base,
+            remaining: self.remaining,
+            _elements: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<(usize, &'a T)> {
+        let index = self.ones.next()?;
+        self.remaining -= 1;
+        // SAFETY: `ones` only yields the indices of occupied slots, and the slab is borrowed for
+        // `'a`.
+        Some((index, unsafe { &*self.base.add(index) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> Debug for Iter<'a, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// An iterator over the occupied slots of a [`GenericSlab`], as pairs of the index and a mutable
+/// reference to the element.
+///
+/// Returned by [`GenericSlab::iter_mut`].
+pub struct IterMut<'a, T> {
+    ones: Ones<'a>,
+    base: *mut T,
+    remaining: usize,
+    _elements: PhantomData<&'a mut T>,
+}
+
+// SAFETY: `IterMut` hands out `&'a mut T`s to distinct elements, so sending it to another thread
+// is sound exactly when sending `&'a mut T` is, which is when `T: Send`.
+unsafe impl<'a, T: Send> Send for IterMut<'a, T> {}
+
+// SAFETY: a shared `&IterMut` can't reach the elements at all, since `next` takes `&mut self`, so
+// `T: Sync` (as for `&'a mut T`) is more than enough.
+unsafe impl<'a, T: Sync> Sync for IterMut<'a, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = (usize, &'a mut T);
+
+    fn next(&mut self) -> Option<(usize, &'a mut T)> {
+        let index = self.ones.next()?;
+        self.remaining -= 1;
+        // SAFETY: `ones` only yields the indices of occupied slots, each at most once, so the
+        // references don't alias, and the slab is mutably borrowed for `'a`.
+        Some((index, unsafe { &mut *self.base.add(index) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+impl<'a, T> FusedIterator for IterMut<'a, T> {}
+
+impl<'a, T> Debug for IterMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IterMut")
+            .field("remaining", &self.remaining)
+            .finish_non_exhaustive()
+    }
+}
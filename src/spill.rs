@@ -0,0 +1,260 @@
+use crate::{ArrayvecStorage, Capacity, GenericArrayVec};
+use alloc::vec::Vec;
+use arrayvec::Array;
+use core::fmt::{self, Debug};
+use core::iter::FromIterator;
+use core::ops::{Deref, DerefMut};
+use core::slice;
+
+/// A vector that stores up to `N` elements inline, and moves them to the heap once it would
+/// overflow.
+///
+/// A `SpillVec` starts out backed by a [`GenericArrayVec<T, N>`]. The first push that would exceed
+/// its capacity moves all of its elements into a heap-allocated [`Vec<T>`], which then backs the
+/// `SpillVec` from that point on. Either way, it dereferences to a slice, so iteration and
+/// indexing work the same in both states.
+///
+/// Requires the `alloc` feature.
+///
+/// ```rust
+/// use generic_arrayvec::typenum::U2;
+/// use generic_arrayvec::SpillVec;
+///
+/// let mut vec = SpillVec::<i32, U2>::new();
+/// vec.push(1);
+/// vec.push(2);
+/// assert!(!vec.is_spilled());
+///
+/// vec.push(3);
+/// assert!(vec.is_spilled());
+/// assert_eq!(&vec[..], &[1, 2, 3]);
+/// ```
+#[derive(Clone)]
+pub struct SpillVec<T, N>(Repr<T, N>)
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>;
+
+#[derive(Clone)]
+enum Repr<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    Inline(GenericArrayVec<T, N>),
+    Heap(Vec<T>),
+}
+
+impl<T, N> SpillVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    /// Creates a new, empty `SpillVec` that stores its elements inline.
+    pub fn new() -> Self {
+        SpillVec(Repr::Inline(GenericArrayVec::new()))
+    }
+
+    /// Returns `true` if the elements have been moved to the heap.
+    pub fn is_spilled(&self) -> bool {
+        match &self.0 {
+            Repr::Inline(_) => false,
+            Repr::Heap(_) => true,
+        }
+    }
+
+    /// Returns the number of elements in the vector.
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements the vector can hold without moving (or, once spilled,
+    /// reallocating) its storage.
+    pub fn capacity(&self) -> usize {
+        match &self.0 {
+            Repr::Inline(vec) => vec.capacity(),
+            Repr::Heap(vec) => vec.capacity(),
+        }
+    }
+
+    /// Appends an element to the back of the vector.
+    ///
+    /// If the inline storage is full, all elements are moved to the heap first.
+    pub fn push(&mut self, element: T) {
+        match &mut self.0 {
+            Repr::Inline(vec) => {
+                if let Err(err) = vec.try_push(element) {
+                    let mut heap = Vec::with_capacity(vec.len() * 2 + 1);
+                    heap.extend(vec.drain(..));
+                    heap.push(err.element());
+                    self.0 = Repr::Heap(heap);
+                }
+            }
+            Repr::Heap(vec) => vec.push(element),
+        }
+    }
+
+    /// Removes the last element of the vector and returns it, or `None` if it's empty.
+    pub fn pop(&mut self) -> Option<T> {
+        match &mut self.0 {
+            Repr::Inline(vec) => vec.pop(),
+            Repr::Heap(vec) => vec.pop(),
+        }
+    }
+
+    /// Shortens the vector to `len` elements, dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than the vector's current length.
+    pub fn truncate(&mut self, len: usize) {
+        match &mut self.0 {
+            Repr::Inline(vec) => vec.truncate(len),
+            Repr::Heap(vec) => vec.truncate(len),
+        }
+    }
+
+    /// Removes all elements from the vector.
+    ///
+    /// This doesn't move the elements back inline if the vector has spilled.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns a slice containing all elements of the vector.
+    pub fn as_slice(&self) -> &[T] {
+        match &self.0 {
+            Repr::Inline(vec) => vec.as_slice(),
+            Repr::Heap(vec) => vec.as_slice(),
+        }
+    }
+
+    /// Returns a mutable slice containing all elements of the vector.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match &mut self.0 {
+            Repr::Inline(vec) => vec.as_mut_slice(),
+            Repr::Heap(vec) => vec.as_mut_slice(),
+        }
+    }
+
+    /// Converts `self` into a heap-allocated `Vec<T>`, moving the elements if they are stored
+    /// inline.
+    pub fn into_vec(self) -> Vec<T> {
+        match self.0 {
+            Repr::Inline(vec) => vec.into_iter().collect(),
+            Repr::Heap(vec) => vec,
+        }
+    }
+}
+
+impl<T, N> Default for SpillVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    fn default() -> Self {
+        SpillVec::new()
+    }
+}
+
+impl<T, N> Debug for SpillVec<T, N>
+where
+    T: Debug,
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T, N> Deref for SpillVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, N> DerefMut for SpillVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, N> From<GenericArrayVec<T, N>> for SpillVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    fn from(vec: GenericArrayVec<T, N>) -> Self {
+        SpillVec(Repr::Inline(vec))
+    }
+}
+
+impl<T, N> Extend<T> for SpillVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for element in iter {
+            self.push(element);
+        }
+    }
+}
+
+impl<T, N> FromIterator<T> for SpillVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut vec = SpillVec::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'a, T, N> IntoIterator for &'a SpillVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, N> IntoIterator for &'a mut SpillVec<T, N>
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
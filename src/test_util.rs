@@ -0,0 +1,73 @@
+use crate::{ArrayvecStorage, Capacity, GenericArrayVec};
+use arrayvec::Array;
+use core::mem::{align_of, size_of};
+use generic_array::GenericArray;
+
+/// Asserts that `GenericArrayVec<T, N>` is no larger than its elements plus its length field,
+/// rounded up to its alignment.
+///
+/// The length is stored using the smallest integer type that fits `N` (or a `usize` with the
+/// `uniform-index` feature), so this is the most space a vector should take up. Call it from
+/// your own tests to check that the vectors you use aren't bloated.
+///
+/// **Panics** if the vector is larger than expected.
+///
+/// ```rust
+/// use generic_arrayvec::test_util::assert_layout;
+/// use generic_arrayvec::typenum::{U100, U1000};
+///
+/// assert_layout::<u8, U100>();
+/// assert_layout::<u32, U1000>();
+/// ```
+pub fn assert_layout<T, N>()
+where
+    N: Capacity<T>,
+    ArrayvecStorage<T, N>: Array<Item = T>,
+{
+    let elements = size_of::<GenericArray<T, N>>();
+    let index = size_of::<<ArrayvecStorage<T, N> as Array>::Index>();
+    let expected = (elements + index).next_multiple_of(align_of::<GenericArrayVec<T, N>>());
+    let actual = size_of::<GenericArrayVec<T, N>>();
+
+    assert!(
+        actual <= expected,
+        "a GenericArrayVec of capacity {} takes up {} bytes, expected at most {} ({} for the \
+         elements, {} for the length)",
+        N::USIZE,
+        actual,
+        expected,
+        elements,
+        index,
+    );
+}
+
+/// Runs a block once for each of the given capacities, with the capacity type bound to a name of
+/// your choosing.
+///
+/// Each capacity gets its own copy of the block, in which the name is a type alias for that
+/// capacity, so the block is type-checked separately for each one. This saves writing out the
+/// same test for every capacity you want to cover.
+///
+/// Requires the `test-util` feature.
+///
+/// ```rust
+/// use generic_arrayvec::for_each_capacity;
+/// use generic_arrayvec::typenum::{U1, U2, U4, U8};
+/// use generic_arrayvec::typenum::Unsigned;
+/// use generic_arrayvec::GenericArrayVec;
+///
+/// for_each_capacity!(U1, U2, U4, U8 => |N| {
+///     let vec: GenericArrayVec<u32, N> = (0..).take(N::USIZE).collect();
+///     assert!(vec.is_full());
+/// });
+/// ```
+#[macro_export]
+macro_rules! for_each_capacity {
+    ($($capacity:ty),+ $(,)? => |$name:ident| $body:block) => {
+        $({
+            #[allow(dead_code)]
+            type $name = $capacity;
+            $body
+        })+
+    };
+}
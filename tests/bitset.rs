@@ -0,0 +1,46 @@
+use generic_arrayvec::bitset::GenericBitSet;
+use generic_arrayvec::typenum::{U0, U1, U16, U17, U8};
+
+#[test]
+fn test_byte_len_rounds_up() {
+    assert_eq!(GenericBitSet::<U0>::new().as_bytes().len(), 0);
+    assert_eq!(GenericBitSet::<U1>::new().as_bytes().len(), 1);
+    assert_eq!(GenericBitSet::<U8>::new().as_bytes().len(), 1);
+    assert_eq!(GenericBitSet::<U16>::new().as_bytes().len(), 2);
+    assert_eq!(GenericBitSet::<U17>::new().as_bytes().len(), 3);
+}
+
+#[test]
+fn test_set_clear_get() {
+    let mut bits = GenericBitSet::<U17>::new();
+    for &i in &[0, 7, 8, 16] {
+        bits.set(i);
+    }
+    bits.set(8);
+    assert_eq!(bits.count_ones(), 4);
+    assert_eq!(bits.as_bytes(), &[0x81, 0x01, 0x01]);
+
+    bits.clear(7);
+    assert!(!bits.get(7));
+    assert!(bits.get(16));
+    assert!(bits.iter_ones().eq([0, 8, 16].iter().copied()));
+    assert_eq!(format!("{:?}", bits), "{0, 8, 16}");
+
+    bits.clear_all();
+    assert_eq!(bits.iter_ones().next(), None);
+}
+
+#[test]
+fn test_iter_ones_skips_empty_bytes() {
+    let mut bits = GenericBitSet::<U17>::new();
+    bits.set(16);
+
+    assert!(bits.iter_ones().eq(Some(16)));
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_set_out_of_range() {
+    // Bits 17 to 23 exist in the last byte, but not in the set.
+    GenericBitSet::<U17>::new().set(17);
+}
@@ -0,0 +1,23 @@
+#![cfg(feature = "const-generics")]
+
+use generic_arrayvec::typenum::U8;
+use generic_arrayvec::{
+    ConstArrayString, ConstArrayVec, GenericArrayString, GenericArrayStringExt, GenericArrayVec,
+    GenericArrayVecExt,
+};
+
+#[test]
+fn test_const_aliases_are_the_typenum_types() {
+    let vec: GenericArrayVec<u8, U8> = ConstArrayVec::<u8, 8>::generic_from_pattern(&[1].into());
+    let string: GenericArrayString<U8> = ConstArrayString::<8>::generic_from("abc").unwrap();
+
+    assert_eq!(&vec[..], &[1; 8]);
+    assert_eq!(string.generic_concat(&string).capacity(), 16);
+}
+
+#[test]
+fn test_const_alias_large_capacity() {
+    let string = ConstArrayString::<1024>::generic_from_truncating(&"x".repeat(2000));
+
+    assert_eq!(string.len(), 1024);
+}
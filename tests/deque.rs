@@ -0,0 +1,116 @@
+use generic_arrayvec::deque::GenericArrayDeque;
+use generic_arrayvec::typenum::{U0, U4};
+use std::rc::Rc;
+
+#[test]
+fn test_deque_push_pop_both_ends() {
+    let mut deque = GenericArrayDeque::<i32, U4>::new();
+    assert_eq!(deque.pop_front(), None);
+    assert_eq!(deque.pop_back(), None);
+
+    deque.push_back(2);
+    deque.push_front(1);
+    deque.push_back(3);
+
+    assert_eq!(deque.front(), Some(&1));
+    assert_eq!(deque.back(), Some(&3));
+    assert_eq!(deque.pop_back(), Some(3));
+    assert_eq!(deque.pop_front(), Some(1));
+    assert_eq!(deque.pop_front(), Some(2));
+    assert!(deque.is_empty());
+}
+
+#[test]
+fn test_deque_full_and_wrap_around() {
+    let mut deque = GenericArrayDeque::<i32, U4>::new();
+    for i in 0..4 {
+        deque.push_back(i);
+    }
+
+    assert!(deque.is_full());
+    assert_eq!(deque.try_push_back(4).unwrap_err().element(), 4);
+    assert_eq!(deque.try_push_front(-1).unwrap_err().element(), -1);
+
+    // Move the front along so that the elements wrap around the end of the storage.
+    assert_eq!(deque.pop_front(), Some(0));
+    assert_eq!(deque.pop_front(), Some(1));
+    deque.push_back(4);
+    deque.push_back(5);
+
+    assert!(deque.is_full());
+    assert_eq!(deque.as_slices(), (&[2, 3][..], &[4, 5][..]));
+    assert!(deque.iter().eq(&[2, 3, 4, 5]));
+    assert!(deque.iter().rev().eq(&[5, 4, 3, 2]));
+    assert_eq!(deque.iter().len(), 4);
+    assert_eq!(deque.get(2), Some(&4));
+    assert_eq!(deque.get(4), None);
+
+    *deque.get_mut(3).unwrap() = 50;
+    assert_eq!(format!("{:?}", deque), "[2, 3, 4, 50]");
+}
+
+#[test]
+fn test_deque_push_front_wraps() {
+    let mut deque = GenericArrayDeque::<i32, U4>::new();
+    deque.push_front(3);
+    deque.push_front(2);
+    deque.push_back(4);
+    deque.push_front(1);
+
+    assert!(deque.iter().eq(&[1, 2, 3, 4]));
+    assert_eq!(
+        deque.clone().iter().copied().collect::<Vec<_>>(),
+        [1, 2, 3, 4]
+    );
+}
+
+#[test]
+fn test_deque_zero_capacity() {
+    let mut deque = GenericArrayDeque::<i32, U0>::new();
+
+    assert!(deque.is_full());
+    assert!(deque.try_push_front(1).is_err());
+    assert!(deque.try_push_back(1).is_err());
+    assert_eq!(deque.iter().next(), None);
+}
+
+#[test]
+fn test_deque_drops_elements() {
+    let rc = Rc::new(());
+    let mut deque = GenericArrayDeque::<_, U4>::new();
+    for _ in 0..3 {
+        deque.push_back(Rc::clone(&rc));
+    }
+    deque.pop_front();
+    deque.push_back(Rc::clone(&rc));
+    deque.push_back(Rc::clone(&rc));
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    drop(deque);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_deque_swap_remove_front() {
+    let mut deque = GenericArrayDeque::<i32, U4>::new();
+    deque.push_back(3);
+    deque.push_back(4);
+    deque.push_front(2);
+    deque.push_front(1);
+
+    // The front element fills the hole.
+    assert_eq!(deque.swap_remove_front(3), 4);
+    assert!(deque.iter().eq(&[2, 3, 1]));
+
+    // Removing the front itself just pops it.
+    assert_eq!(deque.swap_remove_front(0), 2);
+    assert!(deque.iter().eq(&[3, 1]));
+}
+
+#[test]
+#[should_panic]
+fn test_deque_swap_remove_front_out_of_bounds() {
+    let mut deque = GenericArrayDeque::<i32, U4>::new();
+    deque.push_back(1);
+    deque.swap_remove_front(1);
+}
@@ -1,6 +1,10 @@
+use generic_arrayvec::arrayvec::{Array, ArrayVec};
+use generic_arrayvec::bytes::GenericArrayVecBytesExt;
 use generic_arrayvec::generic_array::GenericArray;
-use generic_arrayvec::typenum::{U10, U41, U5};
+use generic_arrayvec::typenum::{U0, U1, U10, U2, U3, U300, U41, U5, U9};
 use generic_arrayvec::*;
+use std::cell::Cell;
+use std::rc::Rc;
 
 #[test]
 fn test_vec_simple() {
@@ -28,6 +32,48 @@ fn test_vec_from_generic_array() {
     assert_zero_to_four(&vec);
 }
 
+#[test]
+fn test_vec_from_generic_array_moves() {
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let arr = GenericArray::from([DropCounter(&drops), DropCounter(&drops)]);
+    let vec = GenericArrayVec::generic_from(arr);
+
+    assert_eq!(vec.len(), 2);
+    assert_eq!(drops.get(), 0);
+    drop(vec);
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn test_vec_from_array_into_iter() {
+    let mut iter =
+        IntoIterator::into_iter([String::from("a"), String::from("b"), String::from("c")]);
+    iter.next();
+
+    let vec = GenericArrayVec::<String, U5>::generic_from_array_into_iter(iter).unwrap();
+    assert_eq!(&vec[..], &["b", "c"]);
+
+    let iter = IntoIterator::into_iter([String::from("a"), String::from("b"), String::from("c")]);
+    let err = GenericArrayVec::<String, U2>::generic_from_array_into_iter(iter).unwrap_err();
+    assert_eq!(err.element().len(), 3);
+}
+
+#[test]
+fn test_vec_from_empty_pattern() {
+    let pattern = GenericArray::<u8, U0>::default();
+    let vec = GenericArrayVec::<u8, U5>::generic_from_pattern(&pattern);
+
+    assert!(vec.is_empty());
+}
+
 #[test]
 fn test_vec_from_iter() {
     let vec: GenericArrayVec<i32, U10> = (0..10).collect();
@@ -43,6 +89,1101 @@ fn test_vec_into_generic_array() {
     assert_zero_to_four(&arr);
 }
 
+#[test]
+fn test_vec_try_extend_stops_at_overflow() {
+    let mut vec = GenericArrayVec::<i32, U5>::new();
+    let mut source = 0..10;
+
+    let err = vec.try_extend(source.by_ref()).unwrap_err();
+    assert_eq!(err.element(), 5);
+    assert_eq!(&vec[..], &[0, 1, 2, 3, 4]);
+    assert_eq!(source.next(), Some(6));
+}
+
+#[test]
+fn test_vec_reset_zeroed() {
+    let mut vec = GenericArrayVec::<u8, U10>::new();
+    vec.extend(1..=10);
+    vec.truncate(4);
+
+    vec.reset_zeroed();
+    assert!(vec.is_empty());
+
+    // Expose the spare region to check that it was wiped too.
+    unsafe { vec.set_len(10) };
+    assert_eq!(&vec[..], &[0; 10]);
+}
+
+#[test]
+fn test_vec_native_arrayvec_round_trip() {
+    let mut native = ArrayVec::<[String; 5]>::new();
+    native.push(String::from("a"));
+    native.push(String::from("b"));
+
+    let vec = GenericArrayVec::<String, U5>::from_native_arrayvec(native);
+    assert_eq!(vec.len(), 2);
+    assert_eq!(vec.capacity(), 5);
+
+    let native: ArrayVec<[String; 5]> = vec.to_native_arrayvec();
+    assert_eq!(&native[..], &["a", "b"]);
+}
+
+#[test]
+fn test_vec_dedup_all_drops_duplicates() {
+    let a = Rc::new(0);
+    let b = Rc::new(1);
+    let mut vec: GenericArrayVec<Rc<i32>, U10> = vec![&a, &b, &a, &a, &b]
+        .into_iter()
+        .map(Rc::clone)
+        .collect();
+
+    vec.dedup_all();
+    assert_eq!(&vec[..], &[a.clone(), b.clone()]);
+    assert_eq!(Rc::strong_count(&a), 2);
+    assert_eq!(Rc::strong_count(&b), 2);
+}
+
+#[test]
+fn test_gvec() {
+    let mut vec: GVec<i32, U5> = (0..3).collect();
+
+    vec.insert(0, -1);
+    assert_eq!(vec.try_push(3), Ok(()));
+    assert!(vec.try_push(4).is_err());
+    assert_eq!(vec.swap_remove(0), -1);
+    assert_eq!(
+        vec.into_generic_array().unwrap_err().into_inner(),
+        [3, 0, 1, 2][..]
+    );
+}
+
+#[test]
+fn test_fixed_seq_generic_sum() {
+    fn double_then_sum<S>(mut seq: S) -> i32
+    where
+        S: FixedSeqMut<Item = i32>,
+    {
+        for x in seq.fixed_iter_mut() {
+            *x *= 2;
+        }
+        assert_eq!(seq.fixed_iter().count(), 4);
+
+        seq.fixed_into_iter().sum()
+    }
+
+    let vec: GenericArrayVec<i32, U5> = (1..=4).collect();
+    let gvec: GVec<i32, U10> = (1..=4).collect();
+
+    assert_eq!(double_then_sum(vec), 20);
+    assert_eq!(double_then_sum(gvec), 20);
+}
+
+#[test]
+fn test_generic_from_arrays_moves_elements() {
+    let rc = Rc::new(());
+    let a = GenericArray::from([Rc::clone(&rc)]);
+    let b = GenericArray::from([Rc::clone(&rc), Rc::clone(&rc)]);
+
+    let vec = GenericArrayVec::generic_from_arrays(a, b);
+    assert_eq!(vec.len(), 3);
+    assert_eq!(Rc::strong_count(&rc), 4);
+}
+
+#[test]
+fn test_dyn_fixed_vec_boxed() {
+    let mut vecs: Vec<Box<dyn DynFixedVec<i32>>> = vec![
+        Box::new(GenericArrayVec::<i32, U2>::generic_from([1, 2])),
+        Box::new(GenericArrayVec::<i32, U10>::new()),
+    ];
+
+    for vec in &mut vecs {
+        vec.as_mut_slice().iter_mut().for_each(|x| *x *= 10);
+    }
+
+    assert_eq!(vecs[0].as_slice(), &[10, 20]);
+    assert_eq!(vecs[0].len(), 2);
+    assert!(vecs[1].is_empty());
+    assert_eq!(vecs[1].capacity(), 10);
+}
+
+#[test]
+fn test_merge_sorted_is_stable() {
+    // Ordered by the number only, so that the letter shows which of the equal elements is which.
+    #[derive(Debug)]
+    struct Keyed(i32, char);
+
+    impl PartialEq for Keyed {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Keyed {}
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    let a = GenericArrayVec::generic_from([Keyed(1, 'a'), Keyed(2, 'a'), Keyed(2, 'b')]);
+    let b = GenericArrayVec::generic_from([Keyed(0, 'c'), Keyed(2, 'c')]);
+    let merged = GenericArrayVec::merge_sorted(a, b);
+
+    let letters: String = merged.iter().map(|k| k.1).collect();
+    assert_eq!(letters, "caabc");
+}
+
+#[test]
+fn test_generic_clone_from_matches_clone() {
+    // Counts the calls to `clone_from`, to check that existing elements are reused.
+    #[derive(Debug, PartialEq)]
+    struct Tracked(i32, Rc<Cell<usize>>);
+
+    impl Clone for Tracked {
+        fn clone(&self) -> Self {
+            Tracked(self.0, Rc::clone(&self.1))
+        }
+
+        fn clone_from(&mut self, source: &Self) {
+            self.0 = source.0;
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    let reused = Rc::new(Cell::new(0));
+    let make = |range: std::ops::Range<i32>| -> GenericArrayVec<Tracked, U5> {
+        range.map(|i| Tracked(i, Rc::clone(&reused))).collect()
+    };
+
+    for &(dest_len, source_len) in &[(3, 1), (1, 3), (2, 2), (0, 4), (4, 0)] {
+        let source = make(10..10 + source_len);
+        let expected_reuses = dest_len.min(source_len) as usize;
+
+        let mut dest = make(0..dest_len);
+        reused.set(0);
+        dest.generic_clone_from(&source);
+        assert_eq!(dest, source.clone());
+        assert_eq!(reused.get(), expected_reuses);
+
+        let mut dest = GVec::from(make(0..dest_len));
+        let source = GVec::from(source);
+        reused.set(0);
+        dest.clone_from(&source);
+        assert_eq!(dest, source.clone());
+        assert_eq!(reused.get(), expected_reuses);
+    }
+}
+
+#[test]
+fn test_extract_if_modifies_kept_elements() {
+    let mut vec: GenericArrayVec<i32, U10> = (1..=6).collect();
+    let removed: Vec<_> = vec
+        .generic_extract_if(|x| {
+            *x *= 10;
+            *x > 30
+        })
+        .collect();
+
+    assert_eq!(removed, [40, 50, 60]);
+    assert_eq!(&vec[..], &[10, 20, 30]);
+}
+
+#[test]
+fn test_extract_if_early_drop_keeps_unvisited() {
+    let mut vec: GenericArrayVec<i32, U10> = (0..8).collect();
+    let mut visited = 0;
+    {
+        let mut iter = vec.generic_extract_if(|x| {
+            visited += 1;
+            *x % 2 == 0
+        });
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(2));
+    }
+
+    assert_eq!(visited, 3);
+    assert_eq!(&vec[..], &[1, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn test_extract_if_drops() {
+    let rc = Rc::new(());
+    let mut vec: GenericArrayVec<_, U10> = (0..6).map(|i| (i, Rc::clone(&rc))).collect();
+
+    let removed: Vec<_> = vec.generic_extract_if(|(i, _)| *i < 2).collect();
+    assert_eq!(Rc::strong_count(&rc), 7);
+    drop(removed);
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    // Dropping the iterator without running it removes nothing.
+    vec.generic_extract_if(|_| true);
+    assert_eq!(vec.len(), 4);
+
+    drop(vec);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_extract_if_panicking_predicate() {
+    let rc = Rc::new(());
+    let mut vec: GenericArrayVec<_, U10> = (0..6).map(|i| (i, Rc::clone(&rc))).collect();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.generic_extract_if(|(i, _)| match *i {
+            3 => panic!("oops"),
+            i => i % 2 == 0,
+        })
+        .for_each(drop);
+    }));
+    assert!(result.is_err());
+
+    let kept: Vec<_> = vec.iter().map(|(i, _)| *i).collect();
+    assert_eq!(kept, [1, 3, 4, 5]);
+    drop(vec);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_into_generic_chunks_moves_and_drops() {
+    let rc = Rc::new(());
+    let vec: GenericArrayVec<_, U10> = (0..7).map(|_| Rc::clone(&rc)).collect();
+
+    let mut chunks = vec.into_generic_chunks::<U2>();
+    assert_eq!(chunks.len(), 4);
+    let first = chunks.next().unwrap();
+    assert!(first.is_full());
+    assert_eq!(Rc::strong_count(&rc), 8);
+
+    drop(chunks);
+    assert_eq!(Rc::strong_count(&rc), 3);
+    drop(first);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_into_generic_chunks_exact_multiple() {
+    let vec: GenericArrayVec<i32, U10> = (0..4).collect();
+    let lens: Vec<_> = vec.into_generic_chunks::<U2>().map(|c| c.len()).collect();
+    assert_eq!(lens, [2, 2]);
+
+    let empty = GenericArrayVec::<i32, U10>::new();
+    assert_eq!(empty.into_generic_chunks::<U2>().next(), None);
+}
+
+#[test]
+fn test_insert_sorted() {
+    let mut vec = GenericArrayVec::<(i32, char), U5>::new();
+
+    assert_eq!(vec.generic_insert_sorted((5, 'a')), Ok(0));
+    assert_eq!(vec.generic_insert_sorted((1, 'a')), Ok(0));
+    assert_eq!(vec.generic_insert_sorted((9, 'a')), Ok(2));
+    assert_eq!(vec.generic_insert_sorted((5, 'b')), Ok(2));
+    assert_eq!(vec.generic_insert_sorted((3, 'a')), Ok(1));
+    assert_eq!(
+        &vec[..],
+        &[(1, 'a'), (3, 'a'), (5, 'a'), (5, 'b'), (9, 'a')]
+    );
+
+    let err = vec.generic_insert_sorted((4, 'a')).unwrap_err();
+    assert_eq!(err.element(), (4, 'a'));
+    assert_eq!(vec.len(), 5);
+}
+
+#[test]
+fn test_chain2_boundaries() {
+    let empty = GenericArrayVec::<i32, U2>::new();
+    let vec: GenericArrayVec<i32, U5> = (0..3).collect();
+
+    let chain = Chain2::new(&empty, &vec);
+    assert_eq!(chain.len(), 3);
+    assert_eq!(chain.get(0), Some(&0));
+    assert_eq!(chain.get(3), None);
+
+    let chain = Chain2::new(&vec, &empty);
+    assert_eq!(chain.get(2), Some(&2));
+    assert_eq!(chain.get(3), None);
+    assert_eq!(
+        (&chain).into_iter().rev().copied().collect::<Vec<_>>(),
+        [2, 1, 0]
+    );
+    assert_eq!(format!("{:?}", chain), "[0, 1, 2]");
+
+    assert!(Chain2::new(&empty, &empty).is_empty());
+}
+
+#[test]
+fn test_concat_all_overflow() {
+    let parts: Vec<GenericArrayVec<i32, U2>> = vec![(0..2).collect(), (2..3).collect()];
+    assert!(concat_all(parts).is_err());
+
+    let empty: Vec<GenericArrayVec<i32, U2>> = Vec::new();
+    assert!(concat_all(empty).unwrap().is_empty());
+}
+
+#[test]
+fn test_reinterpret_keeps_length_and_drops_once() {
+    #[repr(transparent)]
+    struct Wrapper(Rc<()>);
+
+    let rc = Rc::new(());
+    let mut vec = GenericArrayVec::<_, U5>::new();
+    vec.push(Rc::clone(&rc));
+    vec.push(Rc::clone(&rc));
+
+    let wrapped: GenericArrayVec<Wrapper, U5> = unsafe { vec.reinterpret() };
+    assert_eq!(wrapped.len(), 2);
+    assert_eq!(Rc::strong_count(&wrapped[0].0), 3);
+
+    drop(wrapped);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_push_if_absent_duplicate_when_full() {
+    let mut vec = GenericArrayVec::<i32, U2>::generic_from([1, 2]);
+
+    // A duplicate is reported as already present, even though there's no room for it.
+    assert_eq!(vec.push_if_absent(2), Ok(false));
+    assert_eq!(vec.push_if_absent(3).unwrap_err().element(), 3);
+    assert_eq!(&vec[..], &[1, 2]);
+}
+
+#[test]
+fn test_fixed_chunks_pulls_lazily() {
+    let pulled = Cell::new(0);
+    let source = (0..5).inspect(|_| pulled.set(pulled.get() + 1));
+    let mut chunks = source.fixed_chunks::<U2>();
+
+    assert_eq!(&chunks.next().unwrap()[..], &[0, 1]);
+    assert_eq!(pulled.get(), 2);
+    assert_eq!(&chunks.next().unwrap()[..], &[2, 3]);
+    assert_eq!(pulled.get(), 4);
+    assert_eq!(&chunks.next().unwrap()[..], &[4]);
+    assert!(chunks.next().is_none());
+    assert!(chunks.next().is_none());
+}
+
+#[test]
+fn test_fixed_chunks_exact_and_empty() {
+    let lens: Vec<_> = (0..4).fixed_chunks::<U2>().map(|c| c.len()).collect();
+    assert_eq!(lens, [2, 2]);
+    assert_eq!((0..4).fixed_chunks::<U2>().size_hint(), (2, Some(2)));
+
+    assert!(std::iter::empty::<i32>()
+        .fixed_chunks::<U2>()
+        .next()
+        .is_none());
+}
+
+#[test]
+fn test_replace_storage_transfers_ownership() {
+    let rc = Rc::new(());
+    let mut vec = GenericArrayVec::<_, U5>::new();
+    vec.push(rc.clone());
+    vec.push(rc.clone());
+
+    let fresh = GenericArray::<_, U5>::clone_from_slice(&[
+        rc.clone(),
+        rc.clone(),
+        rc.clone(),
+        rc.clone(),
+        rc.clone(),
+    ]);
+    let (mut old, len) = vec.replace_storage(fresh);
+    assert_eq!(len, 2);
+    assert_eq!(vec.len(), 5);
+    assert_eq!(Rc::strong_count(&rc), 8);
+
+    for slot in &mut old[..len] {
+        unsafe { slot.assume_init_drop() };
+    }
+    drop(vec);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_generic_fold_panicking_closure() {
+    let rc = Rc::new(());
+    let vec: GenericArrayVec<_, U5> = (0..5).map(|i| (i, Rc::clone(&rc))).collect();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.generic_fold(0, |acc, (i, _)| match i {
+            2 => panic!("oops"),
+            i => acc + i,
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_capacity_aliases() {
+    let vec: GenericArrayVec4<i32> = (0..4).collect();
+    assert_eq!(vec.capacity(), 4);
+
+    let string = GenericArrayString16::generic_from("hello").unwrap();
+    assert_eq!(string.capacity(), 16);
+    assert_eq!(GenericArrayVec256::<u8>::new().capacity(), 256);
+}
+
+#[test]
+fn test_with_reported_len_under_and_over_fill() {
+    let mut under = GenericArrayVec::<i32, U5>::with_reported_len(3).unwrap();
+    under.push(1).unwrap();
+    under.push(2).unwrap();
+    assert_eq!(under.remaining(), 1);
+    assert_eq!(
+        under.finish(),
+        Err(LenMismatch {
+            expected: 3,
+            actual: 2,
+        })
+    );
+
+    let mut over = GenericArrayVec::<i32, U5>::with_reported_len(2).unwrap();
+    over.push(1).unwrap();
+    over.push(2).unwrap();
+    assert_eq!(over.push(3).unwrap_err().element(), 3);
+    assert_eq!(
+        over.finish(),
+        Err(LenMismatch {
+            expected: 2,
+            actual: 3,
+        })
+    );
+
+    let empty = GenericArrayVec::<i32, U5>::with_reported_len(0).unwrap();
+    assert!(empty.finish().unwrap().is_empty());
+    assert!(GenericArrayVec::<i32, U5>::with_reported_len(6).is_err());
+}
+
+#[test]
+fn test_copy_to_slice() {
+    let vec: GenericArrayVec<u8, U10> = (1..=4).collect();
+
+    let mut short = [0; 3];
+    assert_eq!(vec.copy_to_slice(&mut short), 3);
+    assert_eq!(short, [1, 2, 3]);
+
+    let mut long = [9; 6];
+    assert_eq!(vec.copy_to_slice(&mut long), 4);
+    assert_eq!(long, [1, 2, 3, 4, 9, 9]);
+
+    assert_eq!(vec.copy_to_slice(&mut []), 0);
+}
+
+#[test]
+fn test_interleave_differing_lengths() {
+    let a: GenericArrayVec<_, U5> = (1..=4).collect();
+    let b: GenericArrayVec<_, U2> = (10..=10).collect();
+    let mixed = GenericArrayVec::interleave(a.clone(), b.clone());
+    assert_eq!(&mixed[..], &[1, 10, 2, 3, 4]);
+    assert_eq!(mixed.capacity(), 7);
+
+    let mixed = GenericArrayVec::interleave(b, a);
+    assert_eq!(&mixed[..], &[10, 1, 2, 3, 4]);
+
+    let empty = GenericArrayVec::<i32, U2>::new();
+    let mixed = GenericArrayVec::interleave(empty.clone(), empty);
+    assert!(mixed.is_empty());
+}
+
+#[test]
+fn test_into_iter_rev_dropped_partway() {
+    let rc = Rc::new(());
+    let vec: GenericArrayVec<_, U5> = (0..5).map(|i| (i, Rc::clone(&rc))).collect();
+
+    let mut iter = vec.into_iter_rev();
+    assert_eq!(iter.next().unwrap().0, 4);
+    assert_eq!(iter.next().unwrap().0, 3);
+    assert_eq!(Rc::strong_count(&rc), 4);
+
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_left_pad_with() {
+    use generic_arrayvec::typenum::U8;
+
+    let mut vec = GenericArrayVec::<u8, U8>::new();
+    vec.try_extend_from_slice(&[1, 2, 3]).unwrap();
+    vec.left_pad_with(0);
+    assert_eq!(&vec[..], &[0, 0, 0, 0, 0, 1, 2, 3]);
+
+    vec.left_pad_with(9);
+    assert_eq!(&vec[..], &[0, 0, 0, 0, 0, 1, 2, 3]);
+
+    let mut empty = GenericArrayVec::<u8, U2>::new();
+    empty.left_pad_with(7);
+    assert_eq!(&empty[..], &[7, 7]);
+}
+
+#[test]
+fn test_try_take_generic_array_reuse() {
+    let rc = Rc::new(());
+    let mut vec = GenericArrayVec::<_, U2>::new();
+
+    for _ in 0..3 {
+        vec.push(Rc::clone(&rc));
+        assert!(vec.try_take_generic_array().is_none());
+        assert_eq!(vec.len(), 1);
+
+        vec.push(Rc::clone(&rc));
+        let arr = vec.try_take_generic_array().unwrap();
+        assert!(vec.is_empty());
+        assert_eq!(Rc::strong_count(&rc), 3);
+        drop(arr);
+    }
+
+    drop(vec);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_new_const() {
+    const EMPTY_ONE: GenericArrayVec<String, U1> = new_const();
+    static EMPTY_BIG: GenericArrayVec<u8, U300> = new_const();
+
+    let mut vec = EMPTY_ONE;
+    assert!(vec.is_empty());
+    vec.push(String::from("a"));
+    assert!(vec.is_full());
+
+    assert!(EMPTY_BIG.is_empty());
+    assert_eq!(EMPTY_BIG.capacity(), 300);
+    assert!(new_const::<(), U0>().is_empty());
+}
+
+#[test]
+fn test_fill_ratio() {
+    let mut vec = GenericArrayVec::<u8, U2>::new();
+    assert_eq!(vec.fill_ratio(), 0.0);
+    vec.push(1);
+    assert_eq!(vec.fill_ratio(), 0.5);
+    vec.push(2);
+    assert_eq!(vec.fill_ratio(), 1.0);
+
+    assert_eq!(GenericArrayVec::<u8, U0>::new().fill_ratio(), 0.0);
+}
+
+#[test]
+fn test_fill_from_with_remainder_no_over_pull() {
+    let pulled = Cell::new(0);
+    let source = (0..7).inspect(|_| pulled.set(pulled.get() + 1));
+
+    let (first, rest) = GenericArrayVec::<_, U5>::fill_from_with_remainder(source);
+    assert_eq!(&first[..], &[0, 1, 2, 3, 4]);
+    assert_eq!(pulled.get(), 5);
+
+    let (second, mut rest) = GenericArrayVec::<_, U5>::fill_from_with_remainder(rest);
+    assert_eq!(&second[..], &[5, 6]);
+    assert_eq!(rest.next(), None);
+
+    let (empty, _) = GenericArrayVec::<i32, U0>::fill_from_with_remainder(0..1);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_capacity_keyed_distinguishes_capacities() {
+    use generic_arrayvec::typenum::{U4, U8};
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of<H: Hash>(value: &H) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let small: GenericArrayVec<u8, U4> = (1..=3).collect();
+    let big: GenericArrayVec<u8, U8> = (1..=3).collect();
+    assert_eq!(hash_of(&small), hash_of(&big));
+
+    let small = CapacityKeyed(small);
+    let big = CapacityKeyed(big);
+    assert_ne!(hash_of(&small), hash_of(&big));
+    assert!(small != big);
+    assert_eq!(
+        small,
+        CapacityKeyed((1..=3).collect::<GenericArrayVec<u8, U4>>())
+    );
+
+    let mut set = HashSet::new();
+    assert!(set.insert(small.clone()));
+    assert!(!set.insert(small));
+}
+
+#[test]
+fn test_resize_to_both_directions() {
+    let rc = Rc::new(());
+    let vec: GenericArrayVec<_, U5> = (0..4).map(|i| (i, Rc::clone(&rc))).collect();
+
+    let grown = vec.resize_to::<U10>((9, Rc::clone(&rc)));
+    assert!(grown.is_full());
+    assert_eq!(
+        grown.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+        [0, 1, 2, 3, 9, 9, 9, 9, 9, 9]
+    );
+    assert_eq!(Rc::strong_count(&rc), 11);
+
+    let shrunk = grown.resize_to::<U2>((9, Rc::clone(&rc)));
+    assert_eq!(shrunk.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [0, 1]);
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    assert!(shrunk.resize_to::<U0>((9, Rc::clone(&rc))).is_empty());
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_from_backing_with_len() {
+    let rc = Rc::new(());
+    let arr = GenericArray::<_, U3>::clone_from_slice(&[rc.clone(), rc.clone(), rc.clone()]);
+
+    let vec = GenericArrayVec::from_backing_with_len(arr, 1).unwrap();
+    assert_eq!(vec.len(), 1);
+    assert_eq!(Rc::strong_count(&rc), 2);
+    drop(vec);
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let arr = GenericArray::<u8, U3>::default();
+    assert!(GenericArrayVec::from_backing_with_len(arr, 3)
+        .unwrap()
+        .is_full());
+    assert!(GenericArrayVec::from_backing_with_len(arr, 4).is_err());
+}
+
+#[test]
+fn test_set_replaces_middle() {
+    let mut vec: GenericArrayVec<_, U5> = (1..=3).collect();
+
+    assert_eq!(vec.set(1, 20).unwrap(), 2);
+    assert_eq!(&vec[..], &[1, 20, 3]);
+
+    // Past the end, even with spare capacity.
+    assert_eq!(vec.set(3, 4).unwrap_err().element(), 4);
+    assert_eq!(vec.set(9, 4).unwrap_err().element(), 4);
+    assert_eq!(vec.len(), 3);
+}
+
+#[test]
+fn test_drain_while_dropped_early() {
+    let mut vec: GenericArrayVec<_, U10> = [1, 1, 1, 2, 1].iter().copied().collect();
+
+    let mut drain = vec.drain_while(|&x| x == 1);
+    assert_eq!(drain.next(), Some(1));
+    drop(drain);
+    assert_eq!(&vec[..], &[2, 1]);
+
+    assert_eq!(vec.drain_while(|&x| x == 1).count(), 0);
+    assert_eq!(vec.drain_while(|_| true).count(), 2);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn test_rows() {
+    let mut flat: GenericArrayVec<_, U10> = (0..5).collect();
+
+    assert_eq!(flat.row::<U2>(0).unwrap().as_slice(), &[0, 1]);
+    assert_eq!(flat.row::<U2>(1).unwrap().as_slice(), &[2, 3]);
+    // Only half of row 2 is filled in.
+    assert_eq!(flat.row::<U2>(2), None);
+    assert_eq!(flat.row::<U2>(usize::MAX), None);
+
+    flat.row_mut::<U2>(1).unwrap()[0] = 20;
+    assert_eq!(&flat[..], &[0, 1, 20, 3, 4]);
+    assert!(flat.row_mut::<U5>(1).is_none());
+}
+
+#[test]
+fn test_try_rotate_bounds() {
+    let mut vec: GenericArrayVec<_, U5> = (1..=3).collect();
+
+    vec.try_rotate_left(3).unwrap();
+    assert_eq!(&vec[..], &[1, 2, 3]);
+    vec.try_rotate_right(2).unwrap();
+    assert_eq!(&vec[..], &[2, 3, 1]);
+
+    // Spare capacity doesn't count.
+    assert_eq!(
+        vec.try_rotate_left(4),
+        Err(OutOfBounds { index: 4, len: 3 })
+    );
+    assert_eq!(
+        vec.try_rotate_right(5),
+        Err(OutOfBounds { index: 5, len: 3 })
+    );
+    assert_eq!(&vec[..], &[2, 3, 1]);
+}
+
+#[test]
+fn test_extend_all_or_nothing_rolls_back() {
+    let rc = Rc::new(());
+    let mut vec = GenericArrayVec::<_, U5>::new();
+    vec.push(Rc::clone(&rc));
+
+    // A non-exact-size iterator.
+    let source = std::iter::repeat_with(|| Rc::clone(&rc))
+        .take(5)
+        .filter(|_| true);
+    assert!(vec.extend_all_or_nothing(source).is_err());
+    assert_eq!(vec.len(), 1);
+    assert_eq!(Rc::strong_count(&rc), 2);
+
+    vec.extend_all_or_nothing(std::iter::repeat(Rc::clone(&rc)).take(4))
+        .unwrap();
+    assert!(vec.is_full());
+    vec.extend_all_or_nothing(std::iter::empty()).unwrap();
+}
+
+#[test]
+fn test_extend_all_or_nothing_panicking_iterator() {
+    let rc = Rc::new(());
+    let mut vec = GenericArrayVec::<_, U5>::new();
+    vec.push(Rc::clone(&rc));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let source = (0..4).map(|i| match i {
+            2 => panic!("oops"),
+            _ => Rc::clone(&rc),
+        });
+        vec.extend_all_or_nothing(source).unwrap();
+    }));
+    assert!(result.is_err());
+
+    assert_eq!(vec.len(), 1);
+    assert_eq!(Rc::strong_count(&rc), 2);
+}
+
+#[test]
+fn test_generic_swap_with_slice() {
+    let mut vec: GenericArrayVec<_, U5> = (1..=3).collect();
+    let mut other = [7, 8, 9];
+    vec.generic_swap_with_slice(&mut other);
+
+    assert_eq!(&vec[..], &[7, 8, 9]);
+    assert_eq!(other, [1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_generic_swap_with_slice_length_mismatch() {
+    let mut vec: GenericArrayVec<_, U5> = (1..=3).collect();
+    // Matches the capacity, but not the length.
+    vec.generic_swap_with_slice(&mut [0; 5]);
+}
+
+#[test]
+#[should_panic(expected = "capacity 2 exceeded")]
+fn test_with_extend_overflow_panics() {
+    let _ = GenericArrayVec::<i32, U2>::new().with_extend(0..10);
+}
+
+#[test]
+fn test_generic_starts_ends_with() {
+    let mut vec: GenericArrayVec<u8, U10> = (1..=4).collect();
+
+    assert!(vec.generic_starts_with(&[1, 2]));
+    assert!(!vec.generic_starts_with(&[2]));
+    assert!(vec.generic_ends_with(&[3, 4]));
+    assert!(!vec.generic_ends_with(&[3]));
+    assert!(vec.generic_starts_with(&[]) && vec.generic_ends_with(&[]));
+
+    // Longer than the vector, though within its capacity.
+    assert!(!vec.generic_starts_with(&[1, 2, 3, 4, 0]));
+    vec.clear();
+    assert!(vec.generic_ends_with(&[]));
+}
+
+#[test]
+fn test_into_generic_arrays() {
+    let rc = Rc::new(());
+    let full: GenericArrayVec<_, U3> = (0..3).map(|i| (i, Rc::clone(&rc))).collect();
+
+    let (first, rest) = full.into_generic_arrays::<U1>().unwrap();
+    assert_eq!(first.len(), 1);
+    assert_eq!(rest.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [1, 2]);
+    drop((first, rest));
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let full: GenericArrayVec<_, U2> = (0..2).collect();
+    let (all, none) = full.into_generic_arrays::<U2>().unwrap();
+    assert_eq!((all.as_slice(), none.len()), (&[0, 1][..], 0));
+
+    let partial: GenericArrayVec<_, U2> = (0..1).collect();
+    assert_eq!(&partial.into_generic_arrays::<U1>().unwrap_err()[..], &[0]);
+}
+
+#[test]
+fn test_sort_dedup() {
+    let mut vec = GenericArrayVec::generic_from([3, 1, 2, 1, 3]);
+    vec.sort_dedup();
+    assert_eq!(&vec[..], &[1, 2, 3]);
+
+    let mut empty = GenericArrayVec::<i32, U5>::new();
+    empty.sort_dedup();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_sort_dedup_drops_duplicates_once() {
+    let drops = Rc::new(Cell::new(0));
+    struct Counted(i32, Rc<Cell<usize>>);
+    impl PartialEq for Counted {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+    impl Eq for Counted {}
+    impl PartialOrd for Counted {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Counted {
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    let mut vec: GenericArrayVec<Counted, U5> = IntoIterator::into_iter([3, 1, 2, 1, 3])
+        .map(|n| Counted(n, drops.clone()))
+        .collect();
+    vec.sort_dedup();
+    assert_eq!(drops.get(), 2);
+    assert!(vec.iter().map(|c| c.0).eq(1..=3));
+
+    drop(vec);
+    assert_eq!(drops.get(), 5);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_as_bytes_live_elements_only() {
+    let mut vec = GenericArrayVec::<u32, U5>::new();
+    assert!(vec.as_bytes().is_empty());
+
+    vec.push(0x0102_0304);
+    vec.push(0x0506_0708);
+    assert_eq!(vec.as_bytes().len(), 8);
+    assert_eq!(&vec.as_bytes()[..4], &0x0102_0304_u32.to_ne_bytes());
+
+    vec.as_bytes_mut()[4..].copy_from_slice(&9_u32.to_ne_bytes());
+    assert_eq!(&vec[..], &[0x0102_0304, 9]);
+}
+
+#[test]
+fn test_full() {
+    use core::convert::TryFrom;
+
+    let partial: GenericArrayVec<u8, U3> = (1..3).collect();
+    let partial = Full::try_from(partial).unwrap_err();
+    assert_eq!(&partial[..], &[1, 2]);
+
+    let mut full = Full::<u8, U3>::from_array([1, 2, 3]);
+    full[0] = 10;
+    assert_eq!(full.len(), 3);
+    assert_eq!(&full[1..], &[2, 3]);
+    assert_eq!(
+        full,
+        Full::try_from(GenericArrayVec::generic_from([10, 2, 3])).unwrap()
+    );
+    assert_eq!(&full.into_vec()[..], &[10, 2, 3]);
+}
+
+#[test]
+fn test_coalesce_intervals() {
+    let mut intervals = GenericArrayVec::generic_from([(0, 2), (2, 5), (7, 8), (8, 9), (10, 11)]);
+    intervals.coalesce(|prev, next| {
+        if prev.1 == next.0 {
+            prev.1 = next.1;
+            Ok(())
+        } else {
+            Err(next)
+        }
+    });
+    assert_eq!(&intervals[..], &[(0, 5), (7, 9), (10, 11)]);
+
+    let mut empty = GenericArrayVec::<i32, U3>::new();
+    empty.coalesce(|_, _| Ok(()));
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_coalesce_drops_merged_once() {
+    let counter = Rc::new(());
+    let mut vec: GenericArrayVec<Rc<()>, U5> = (0..5).map(|_| counter.clone()).collect();
+    assert_eq!(Rc::strong_count(&counter), 6);
+
+    vec.coalesce(|_, _| Ok(()));
+    assert_eq!(vec.len(), 1);
+    assert_eq!(Rc::strong_count(&counter), 2);
+}
+
+#[test]
+fn test_generic_iter_rev_enumerate() {
+    fn reversed_with_index<T, N>(vec: &GenericArrayVec<T, N>) -> Vec<(usize, T)>
+    where
+        T: Copy + PartialEq,
+        N: Capacity<T>,
+        ArrayvecStorage<T, N>: arrayvec::Array<Item = T>,
+    {
+        // `&GenericArrayVec` iterates double-endedly too.
+        assert!(vec.into_iter().rev().eq(vec.generic_iter().rev()));
+
+        vec.generic_iter().rev().copied().enumerate().collect()
+    }
+
+    let vec: GenericArrayVec<char, U5> = "abc".chars().collect();
+    assert_eq!(reversed_with_index(&vec), [(0, 'c'), (1, 'b'), (2, 'a')]);
+    assert_eq!(vec.generic_iter().len(), 3);
+}
+
+#[test]
+fn test_split_off_after() {
+    let mut vec = GenericArrayVec::generic_from([10, 20, 20, 30, 40]);
+    let rest = vec.split_off_after(|&x| x <= 20);
+    assert_eq!(&vec[..], &[10, 20, 20]);
+    assert_eq!(&rest[..], &[30, 40]);
+
+    // Splitting at either end leaves one side empty.
+    let mut all = GenericArrayVec::generic_from([1, 2, 3]);
+    assert!(all.split_off_after(|_| true).is_empty());
+    assert_eq!(all.len(), 3);
+    let none = all.split_off_after(|_| false);
+    assert!(all.is_empty());
+    assert_eq!(&none[..], &[1, 2, 3]);
+}
+
+#[test]
+fn test_as_generic_array_chunks() {
+    let mut vec: GenericArrayVec<u8, U10> = (0..9).collect();
+    assert!(vec.as_generic_array_chunks::<U2>().is_none());
+
+    let chunks = vec.as_generic_array_chunks::<U3>().unwrap();
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[2].as_slice(), &[6, 7, 8]);
+
+    vec.as_generic_array_chunks_mut::<U9>().unwrap()[0][0] = 100;
+    assert_eq!(vec[0], 100);
+
+    // An empty vector is zero chunks of any size.
+    let empty = GenericArrayVec::<u8, U10>::new();
+    assert_eq!(
+        empty.as_generic_array_chunks::<U5>().map(<[_]>::len),
+        Some(0)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_as_generic_array_chunks_zero() {
+    let vec = GenericArrayVec::generic_from([1]);
+    vec.as_generic_array_chunks::<U0>();
+}
+
+#[test]
+fn test_gvec_extend_stops_at_capacity() {
+    let mut vec: GVec<i32, U2> = (0..10).collect();
+    assert_eq!(vec, [0, 1]);
+
+    vec.clear();
+    vec.extend(0..10);
+    assert_eq!(vec, [0, 1]);
+}
+
+#[test]
+fn test_gstring_conversions() {
+    let inner = GenericArrayString::<U5>::from("abc").unwrap();
+    let mut string = GString::from(inner);
+    assert_eq!(string, *"abc");
+    assert_eq!(string.capacity(), 5);
+
+    assert!(string.try_push_str("def").is_err());
+    string.try_push_str("de").unwrap();
+    assert_eq!(format!("{} {:?}", string, string), r#"abcde "abcde""#);
+    let inner: GenericArrayString<U5> = string.into();
+    assert_eq!(&inner[..], "abcde");
+}
+
+#[test]
+fn test_ext_with_only_array_bound() {
+    // The bound `GenericArrayVecExt` had in 0.4.0 is still enough for the methods it had then.
+    fn round_trip<T, N>(arr: GenericArray<T, N>) -> GenericArray<T, N>
+    where
+        N: Capacity<T>,
+        ArrayvecStorage<T, N>: arrayvec::Array,
+    {
+        match GenericArrayVec::generic_from(arr).into_generic_array() {
+            Ok(arr) => arr,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    assert_eq!(
+        round_trip(GenericArray::from([1, 2, 3])).as_slice(),
+        &[1, 2, 3]
+    );
+}
+
+#[test]
+fn test_gvec_single_bound() {
+    fn fill_and_take<T, N>(value: T) -> Option<GenericArray<T, N>>
+    where
+        T: Clone,
+        N: GVecCapacity<T>,
+    {
+        let mut vec = GVec::<T, N>::new();
+        while !vec.is_full() {
+            vec.push(value.clone());
+        }
+
+        vec.into_generic_array().ok()
+    }
+
+    assert_eq!(fill_and_take::<u8, U3>(7).unwrap().as_slice(), &[7, 7, 7]);
+
+    let vec: GenericArrayVec<u8, U3> = GVec::from_array([1, 2, 3]).into_inner();
+    assert_eq!(&vec[..], &[1, 2, 3]);
+}
+
+#[test]
+fn test_map_into_generic_array_not_full() {
+    let mut vec = GenericArrayVec::<u8, U5>::new();
+    vec.extend(1..4);
+
+    let vec = vec.map_into_generic_array::<u16>().unwrap_err();
+    assert_eq!(&vec[..], &[1, 2, 3]);
+}
+
+#[test]
+fn test_max_index_breakpoints() {
+    use generic_arrayvec::plumbing::{IndexForCapacity, MaxIndex};
+    use generic_arrayvec::typenum::{U256, U65535, U65536};
+
+    assert_eq!(<IndexForCapacity<U0> as MaxIndex>::MAX_INDEX, 0);
+    assert_eq!(<IndexForCapacity<U2> as MaxIndex>::MAX_INDEX, 255);
+    assert_eq!(<IndexForCapacity<U256> as MaxIndex>::MAX_INDEX, 65535);
+    assert_eq!(<IndexForCapacity<U65535> as MaxIndex>::MAX_INDEX, 65535);
+    assert_eq!(
+        <IndexForCapacity<U65536> as MaxIndex>::MAX_INDEX,
+        u32::MAX as usize
+    );
+}
+
 #[test]
 fn test_string_from() {
     let string = GenericArrayString::<U10>::generic_from("hello").unwrap();
@@ -60,15 +1201,374 @@ fn test_string_from_byte_string_literal() {
 
 #[test]
 fn test_string_from_byte_string_generic() {
-    let byte_string = GenericArray::from(b"hello".clone());
+    let byte_string = GenericArray::from(*b"hello");
     let string = GenericArrayString::<U5>::generic_from_byte_string(&byte_string).unwrap();
 
     assert_eq!(&string, "hello");
 }
 
+#[test]
+fn test_bytes_from_ints() {
+    let le = GenericArrayVec::<u8, U10>::from_u32_le(&[1, 0x0a0b_0c0d]).unwrap();
+    let be = GenericArrayVec::<u8, U10>::from_u16_be(&[1, 0x0a0b]).unwrap();
+
+    assert_eq!(&le[..], &[1, 0, 0, 0, 0x0d, 0x0c, 0x0b, 0x0a]);
+    assert_eq!(&be[..], &[0, 1, 0x0a, 0x0b]);
+    assert!(GenericArrayVec::<u8, U10>::from_u64_be(&[1, 2]).is_err());
+}
+
+#[test]
+fn test_be_uint_round_trip() {
+    use generic_arrayvec::typenum::U16;
+
+    for &value in &[0, 1, 0xff, 0x100, u64::MAX as u128, u128::MAX] {
+        let vec = GenericArrayVec::<u8, U16>::from_be_uint(value).unwrap();
+        assert_eq!(vec.to_be_uint(), Some(value));
+    }
+
+    assert!(GenericArrayVec::<u8, U16>::from_be_uint(0)
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        &GenericArrayVec::<u8, U16>::from_be_uint(u128::MAX).unwrap()[..],
+        &[0xff; 16]
+    );
+    assert!(GenericArrayVec::<u8, U2>::from_be_uint(0x01_0000).is_err());
+
+    // Leading zeros beyond 16 bytes are fine.
+    let mut padded = GenericArrayVec::<u8, U41>::from_be_uint(7).unwrap();
+    padded.left_pad_with(0);
+    assert_eq!(padded.to_be_uint(), Some(7));
+}
+
+#[test]
+fn test_find_byte_live_region_only() {
+    let mut vec = GenericArrayVec::<u8, U10>::new();
+    vec.try_extend_from_slice(b"a,b,c\n").unwrap();
+    vec.truncate(4);
+
+    assert_eq!(vec.find_byte(b','), Some(1));
+    assert_eq!(vec.rfind_byte(b','), Some(3));
+    // The newline is still in the storage, past the end of the vector.
+    assert_eq!(vec.find_byte(b'\n'), None);
+    assert_eq!(vec.rfind_byte(b'c'), None);
+    assert_eq!(GenericArrayVec::<u8, U10>::new().find_byte(0), None);
+}
+
+#[test]
+fn test_join_strings_capacity() {
+    let parts: GenericArrayVec<GenericArrayString<U5>, U3> = ["ab", "cde", "f"]
+        .iter()
+        .map(|s| GenericArrayString::generic_from(s).unwrap())
+        .collect();
+
+    // 6 bytes of text and 2 separators of 2 bytes each.
+    assert_eq!(&parts.join_strings::<U10>(", ").unwrap()[..], "ab, cde, f");
+    assert!(parts.join_strings::<U9>(", ").is_err());
+    assert_eq!(&parts.join_strings::<U10>("").unwrap()[..], "abcdef");
+
+    let empty = GenericArrayVec::<&str, U2>::new();
+    assert_eq!(&empty.join_strings::<U0>(", ").unwrap()[..], "");
+    let one = GenericArrayVec::<_, U1>::generic_from(["x"]);
+    assert_eq!(&one.join_strings::<U1>(", ").unwrap()[..], "x");
+}
+
+#[test]
+fn test_generic_from_utf8_iter_matches_from_utf8() {
+    let cases: &[&[u8]] = &[
+        b"",
+        "aé€😀".as_bytes(),
+        b"\xc0\x80",         // overlong NUL
+        b"\xed\xa0\x80",     // surrogate
+        b"\xf4\x90\x80\x80", // past U+10FFFF
+        b"ab\xe2\x82",       // truncated
+        b"a\xe2z\xac",       // interrupted
+        b"\x80",
+        b"\xff",
+    ];
+
+    for &bytes in cases {
+        let expected = std::str::from_utf8(bytes).map_err(|e| e.valid_up_to());
+        let actual = GenericArrayString::<U10>::generic_from_utf8_iter(bytes.iter().copied());
+        match (expected, actual) {
+            (Ok(s), Ok(string)) => assert_eq!(&string[..], s),
+            (Err(valid_up_to), Err(e)) => {
+                assert_eq!(
+                    e,
+                    Utf8OrCapacityError::InvalidUtf8 { valid_up_to },
+                    "{:?}",
+                    bytes
+                )
+            }
+            (expected, actual) => panic!("{:?}: {:?} vs {:?}", bytes, expected, actual),
+        }
+    }
+}
+
+#[test]
+fn test_generic_from_utf8_iter_capacity() {
+    // The "€" would end at byte 6.
+    let result = GenericArrayString::<U5>::generic_from_utf8_iter("abc€".bytes());
+    assert_eq!(result, Err(Utf8OrCapacityError::Capacity));
+    let result = GenericArrayString::<U5>::generic_from_utf8_iter("ab€".bytes());
+    assert_eq!(&result.unwrap()[..], "ab€");
+
+    // Stops reading at the error.
+    let mut bytes = b"\xffrest".iter().copied();
+    assert!(GenericArrayString::<U5>::generic_from_utf8_iter(bytes.by_ref()).is_err());
+    assert_eq!(bytes.next(), Some(b'r'));
+}
+
+#[test]
+fn test_gformat() {
+    let value = 7;
+    let string = gformat!(U10, "prefix-{}", value).unwrap();
+    assert_eq!(&string[..], "prefix-7");
+
+    let string = gformat!(U10, "{value:03}|{:x}", 255).unwrap();
+    assert_eq!(&string[..], "007|ff");
+
+    // Overflowing is an error, not a panic, even partway through an argument.
+    assert!(gformat!(U5, "prefix-{}", value).is_err());
+    assert!(gformat!(U9, "prefix-{}", 123).is_err());
+}
+
+#[test]
+fn test_char_boundaries() {
+    let string = GenericArrayString::<U10>::from("añ😀").unwrap();
+    let boundaries: Vec<usize> = string.char_boundaries().collect();
+    assert_eq!(boundaries, [0, 1, 3]);
+    assert!(boundaries.iter().all(|&i| string.is_char_boundary(i)));
+
+    assert_eq!(
+        GenericArrayString::<U10>::new().char_boundaries().next(),
+        None
+    );
+}
+
+#[test]
+fn test_fits_formatted() {
+    assert!(GenericArrayString::<U0>::fits_formatted(format_args!("")));
+    assert!(!GenericArrayString::<U0>::fits_formatted(format_args!("a")));
+
+    let name = "héllo";
+    assert!(GenericArrayString::<U10>::fits_formatted(format_args!(
+        "{}!",
+        name
+    )));
+    assert!(!GenericArrayString::<U5>::fits_formatted(format_args!(
+        "{}",
+        name
+    )));
+    assert!(!GenericArrayString::<U10>::fits_formatted(format_args!(
+        "{:>11}",
+        ""
+    )));
+}
+
+#[test]
+fn test_string_parse() {
+    let s: GenericArrayString<U5> = "hi".parse().unwrap();
+    assert_eq!(&s[..], "hi");
+
+    assert!("too long".parse::<GenericArrayString<U5>>().is_err());
+}
+
+#[test]
+fn test_string_from_truncating_multibyte() {
+    // "aé€" is 1 + 2 + 3 bytes.
+    let s = "aé€";
+
+    assert_eq!(
+        &GenericArrayString::<U10>::generic_from_truncating(s)[..],
+        s
+    );
+    assert_eq!(
+        &GenericArrayString::<U5>::generic_from_truncating(s)[..],
+        "aé"
+    );
+    assert_eq!(
+        &GenericArrayString::<U2>::generic_from_truncating(s)[..],
+        "a"
+    );
+    assert_eq!(
+        &GenericArrayString::<U0>::generic_from_truncating(s)[..],
+        ""
+    );
+}
+
+#[test]
+fn test_string_truncate_chars_multibyte() {
+    // "a€b" is 1 + 3 + 1 bytes.
+    let string = GenericArrayString::<U10>::generic_from("a€b").unwrap();
+
+    for &(max_bytes, expected) in &[(0, ""), (1, "a"), (2, "a"), (3, "a"), (4, "a€"), (9, "a€b")]
+    {
+        let mut truncated = string;
+        truncated.generic_truncate_chars(max_bytes);
+        assert_eq!(&truncated[..], expected);
+    }
+}
+
+#[test]
+fn test_string_display_generic() {
+    fn greet<N>(name: &GenericArrayString<N>) -> String
+    where
+        N: Capacity<u8>,
+        N::ArrayType: Copy,
+        ArrayvecStorage<u8, N>: Array<Item = u8>,
+    {
+        format!("hello, {}!", name)
+    }
+
+    let name = GenericArrayString::<U10>::generic_from("world").unwrap();
+
+    assert_eq!(greet(&name), "hello, world!");
+}
+
 fn assert_zero_to_four<T>(vec: &T)
 where
     T: AsRef<[i32]>,
 {
     assert_eq!(&vec.as_ref()[..5], &[0, 1, 2, 3, 4][..]);
 }
+
+#[cfg(feature = "base64")]
+#[test]
+fn test_base64_round_trip() {
+    use generic_arrayvec::typenum::U8;
+
+    let cases = [
+        "", "Zg==", "Zm8=", "Zm9v", "Zm9vYg==", "Zm9vYmE=", "Zm9vYmFy",
+    ];
+    for (len, &encoded) in cases.iter().enumerate() {
+        let vec = GenericArrayVec::<u8, U10>::from_base64(encoded).unwrap();
+        assert_eq!(&vec[..], &b"foobar"[..len]);
+        assert_eq!(&vec.to_base64::<U8>().unwrap()[..], encoded);
+    }
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn test_from_base64_rejects_non_canonical() {
+    use generic_arrayvec::bytes::FromBase64Error;
+
+    type Vec = GenericArrayVec<u8, U5>;
+    assert_eq!(
+        Vec::from_base64("Zh=="),
+        Err(FromBase64Error::InvalidByte {
+            index: 1,
+            byte: b'h'
+        })
+    );
+    assert_eq!(
+        Vec::from_base64("Zm9="),
+        Err(FromBase64Error::InvalidByte {
+            index: 2,
+            byte: b'9'
+        })
+    );
+    assert_eq!(&Vec::from_base64("Zm8=").unwrap()[..], b"fo");
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn test_from_base64_errors() {
+    use generic_arrayvec::bytes::FromBase64Error;
+
+    type Vec = GenericArrayVec<u8, U5>;
+    assert_eq!(
+        Vec::from_base64("Zm9=v"),
+        Err(FromBase64Error::InvalidLength)
+    );
+    assert_eq!(
+        Vec::from_base64("Zm=v"),
+        Err(FromBase64Error::InvalidByte {
+            index: 2,
+            byte: b'='
+        })
+    );
+    assert_eq!(
+        Vec::from_base64("Z==="),
+        Err(FromBase64Error::InvalidByte {
+            index: 1,
+            byte: b'='
+        })
+    );
+    assert_eq!(
+        Vec::from_base64("Zm9v-A=="),
+        Err(FromBase64Error::InvalidByte {
+            index: 4,
+            byte: b'-'
+        })
+    );
+    assert_eq!(
+        Vec::from_base64("Zm9vYmFy"),
+        Err(FromBase64Error::CapacityExceeded)
+    );
+    // Exactly filling the capacity is fine.
+    assert_eq!(&Vec::from_base64("Zm9vYmE=").unwrap()[..], b"fooba");
+}
+
+#[cfg(feature = "hex")]
+#[test]
+fn test_from_hex_errors() {
+    use generic_arrayvec::bytes::FromHexError;
+
+    type Bytes = GenericArrayVec<u8, U2>;
+
+    assert_eq!(&Bytes::from_hex("").unwrap()[..], &[]);
+    assert_eq!(&Bytes::from_hex("0aFF").unwrap()[..], &[0x0a, 0xff]);
+    assert_eq!(Bytes::from_hex("0a0"), Err(FromHexError::OddLength));
+    assert_eq!(
+        Bytes::from_hex("0a0b0c"),
+        Err(FromHexError::InvalidStringLength)
+    );
+    assert_eq!(
+        Bytes::from_hex("0x"),
+        Err(FromHexError::InvalidHexCharacter { c: 'x', index: 1 }),
+    );
+}
+
+#[cfg(all(feature = "crc", feature = "fnv"))]
+#[test]
+fn test_checksums_ignore_spare_capacity() {
+    let mut vec = GenericArrayVec::<u8, U10>::new();
+    vec.extend(b"junk".iter().copied());
+    vec.clear();
+    vec.extend(b"ab".iter().copied());
+
+    let exact = GenericArrayVec::generic_from(*b"ab");
+    assert_eq!(vec.crc32(), exact.crc32());
+    assert_eq!(vec.fnv1a(), exact.fnv1a());
+}
+
+#[test]
+fn test_gvec_eq_array() {
+    let full = GVec::<_, U3>::from_array([1, 2, 3]);
+    assert_eq!(full, [1, 2, 3]);
+    assert_eq!(full, &[1, 2, 3]);
+    assert_ne!(full, [1, 2, 4]);
+
+    let mut partial = full.clone();
+    partial.pop();
+    assert_ne!(partial, [1, 2, 3]);
+    assert_ne!(partial, [1, 2, 0]);
+
+    // The plain alias compares against slices of arrays.
+    let vec: GenericArrayVec<_, U3> = full.into_inner();
+    assert_eq!(vec, [1, 2, 3][..]);
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn test_gvec_constant_time_eq() {
+    use subtle::ConstantTimeEq;
+
+    let a: GVec<u8, U5> = (1..4).collect();
+    let b: GVec<u8, U5> = (1..4).collect();
+    let c: GVec<u8, U5> = (1..5).collect();
+
+    assert!(bool::from(a.ct_eq(&b)));
+    assert!(!bool::from(a.ct_eq(&c)));
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "miniserde")]
+
+use generic_arrayvec::typenum::{U2, U4};
+use generic_arrayvec::{GString, GVec, GenericArrayString};
+use miniserde::json;
+
+#[test]
+fn test_gvec_round_trip() {
+    let vec: GVec<u32, U4> = (1..=3).collect();
+    let encoded = json::to_string(&vec);
+    assert_eq!(encoded, "[1,2,3]");
+
+    let decoded: GVec<u32, U4> = json::from_str(&encoded).unwrap();
+    assert_eq!(decoded, vec);
+}
+
+#[test]
+fn test_gvec_overflow_is_an_error() {
+    assert!(json::from_str::<GVec<u32, U2>>("[1,2,3]").is_err());
+    assert_eq!(json::from_str::<GVec<u32, U2>>("[1,2]").unwrap(), [1, 2]);
+}
+
+#[test]
+fn test_gstring_round_trip() {
+    let string = GString::from(GenericArrayString::<U4>::from("a\"b").unwrap());
+    let encoded = json::to_string(&string);
+    assert_eq!(encoded, r#""a\"b""#);
+
+    let decoded: GString<U4> = json::from_str(&encoded).unwrap();
+    assert_eq!(decoded, string);
+}
+
+#[test]
+fn test_gstring_overflow_is_an_error() {
+    assert!(json::from_str::<GString<U2>>(r#""abc""#).is_err());
+    assert_eq!(*json::from_str::<GString<U2>>(r#""ab""#).unwrap(), *"ab");
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "serde")]
+
+use generic_arrayvec::typenum::{U3, U5};
+use generic_arrayvec::{GenericArrayString, GenericArrayVec};
+use serde::de::value::{Error, SeqDeserializer, StrDeserializer};
+use serde::Deserialize;
+
+#[test]
+fn test_vec_overflow_error_names_capacity() {
+    let de = SeqDeserializer::<_, Error>::new(vec![1, 2, 3, 4, 5].into_iter());
+    let err = GenericArrayVec::<i32, U3>::deserialize(de).unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "invalid length 4, expected an array with no more than 3 items"
+    );
+}
+
+#[test]
+fn test_string_overflow_error_names_capacity() {
+    let de = StrDeserializer::<Error>::new("too long");
+    let err = GenericArrayString::<U5>::deserialize(de).unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "invalid length 8, expected a string no more than 5 bytes long"
+    );
+}
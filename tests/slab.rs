@@ -0,0 +1,105 @@
+use generic_arrayvec::slab::GenericSlab;
+use generic_arrayvec::typenum::{U0, U3, U9};
+use std::rc::Rc;
+
+#[test]
+fn test_slab_insert_reuses_lowest_vacant_slot() {
+    let mut slab = GenericSlab::<i32, U3>::new();
+    assert_eq!(slab.insert(10), Ok(0));
+    assert_eq!(slab.insert(11), Ok(1));
+    assert_eq!(slab.insert(12), Ok(2));
+    assert!(slab.is_full());
+    assert_eq!(slab.insert(13).unwrap_err().element(), 13);
+
+    assert_eq!(slab.take(0), Some(10));
+    assert_eq!(slab.take(1), Some(11));
+    assert_eq!(slab.len(), 1);
+    assert_eq!(slab.insert(14), Ok(0));
+    assert_eq!(slab.insert(15), Ok(1));
+}
+
+#[test]
+fn test_slab_take_and_put() {
+    let mut slab = GenericSlab::<&str, U9>::new();
+    assert_eq!(slab.put(8, "last"), None);
+    assert_eq!(slab.put(8, "replaced"), Some("last"));
+    assert!(slab.contains(8));
+    assert!(!slab.contains(7));
+    assert!(!slab.contains(9));
+
+    assert_eq!(slab.take(8), Some("replaced"));
+    assert_eq!(slab.take(8), None);
+    assert_eq!(slab.take(100), None);
+    assert!(slab.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_slab_put_out_of_bounds() {
+    let mut slab = GenericSlab::<i32, U3>::new();
+    slab.put(3, 0);
+}
+
+#[test]
+fn test_slab_iter_skips_vacant_slots() {
+    let mut slab = GenericSlab::<i32, U9>::new();
+    for i in 0..9 {
+        slab.insert(i).unwrap();
+    }
+    for i in (0..9).step_by(2) {
+        slab.take(i);
+    }
+
+    for (index, element) in &mut slab {
+        *element += index as i32;
+    }
+    let entries: Vec<_> = slab.iter().map(|(i, &x)| (i, x)).collect();
+    assert_eq!(entries, [(1, 2), (3, 6), (5, 10), (7, 14)]);
+    assert_eq!(slab.iter().len(), 4);
+    assert_eq!(format!("{:?}", slab), "{1: 2, 3: 6, 5: 10, 7: 14}");
+
+    let copy = slab.clone();
+    assert!(copy.iter().eq(slab.iter()));
+}
+
+#[test]
+fn test_slab_zero_capacity() {
+    let mut slab = GenericSlab::<i32, U0>::new();
+    assert!(slab.is_full());
+    assert!(slab.insert(1).is_err());
+    assert_eq!(slab.iter().next(), None);
+}
+
+#[test]
+fn test_slab_drops_elements() {
+    let counter = Rc::new(());
+    let mut slab = GenericSlab::<Rc<()>, U9>::new();
+    for _ in 0..5 {
+        slab.insert(counter.clone()).unwrap();
+    }
+    drop(slab.take(2));
+    assert_eq!(Rc::strong_count(&counter), 5);
+
+    slab.put(0, counter.clone());
+    assert_eq!(Rc::strong_count(&counter), 5);
+
+    drop(slab);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn test_slab_iterators_are_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let mut slab = GenericSlab::<i32, U3>::new();
+    slab.insert(1).unwrap();
+    slab.insert(2).unwrap();
+    assert_send_sync(&slab.iter());
+    assert_send_sync(&slab.iter_mut());
+
+    std::thread::scope(|s| {
+        let iter = slab.iter_mut();
+        s.spawn(move || iter.for_each(|(_, element)| *element *= 10));
+    });
+    assert!(slab.iter().eq([(0, &10), (1, &20)].iter().copied()));
+}
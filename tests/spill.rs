@@ -0,0 +1,34 @@
+#![cfg(feature = "alloc")]
+
+use generic_arrayvec::typenum::U3;
+use generic_arrayvec::SpillVec;
+
+#[test]
+fn test_spill_on_overflow() {
+    let mut vec = SpillVec::<String, U3>::new();
+
+    for i in 0..3 {
+        vec.push(i.to_string());
+    }
+    assert!(!vec.is_spilled());
+    assert_eq!(vec.capacity(), 3);
+
+    vec.push(3.to_string());
+    assert!(vec.is_spilled());
+    assert!(vec.capacity() >= 4);
+    assert_eq!(vec.len(), 4);
+    assert_eq!(&vec[..], ["0", "1", "2", "3"]);
+}
+
+#[test]
+fn test_spill_iter_and_index() {
+    let mut vec: SpillVec<i32, U3> = (0..5).collect();
+
+    assert_eq!(vec[4], 4);
+    for x in &mut vec {
+        *x *= 2;
+    }
+    assert_eq!((&vec).into_iter().sum::<i32>(), 20);
+    assert_eq!(vec.pop(), Some(8));
+    assert_eq!(vec.into_vec(), vec![0, 2, 4, 6]);
+}
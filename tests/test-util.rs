@@ -0,0 +1,31 @@
+#![cfg(feature = "test-util")]
+
+use generic_arrayvec::test_util::assert_layout;
+use generic_arrayvec::typenum::{U0, U1, U255, U256, U65536};
+
+#[test]
+fn test_assert_layout_at_index_breakpoints() {
+    assert_layout::<u8, U0>();
+    assert_layout::<u8, U1>();
+    assert_layout::<u8, U255>();
+    assert_layout::<u8, U256>();
+    assert_layout::<u64, U256>();
+    assert_layout::<u8, U65536>();
+    assert_layout::<(), U256>();
+}
+
+#[test]
+fn test_for_each_capacity() {
+    use generic_arrayvec::typenum::{Unsigned, U2, U4, U8};
+    use generic_arrayvec::{for_each_capacity, GenericArrayVec};
+
+    let mut seen = Vec::new();
+    for_each_capacity!(U0, U1, U2, U4, U8 => |N| {
+        let mut vec = GenericArrayVec::<u8, N>::new();
+        while vec.try_push(0).is_ok() {}
+        assert_eq!(vec.len(), N::USIZE);
+        seen.push(vec.capacity());
+    });
+
+    assert_eq!(seen, [0, 1, 2, 4, 8]);
+}
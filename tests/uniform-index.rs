@@ -0,0 +1,27 @@
+#![cfg(feature = "uniform-index")]
+
+use generic_arrayvec::typenum::U4;
+use generic_arrayvec::{Capacity, GenericArrayVec, GenericArrayVecExt};
+use std::mem::size_of;
+
+fn first<T, N>(vec: &GenericArrayVec<T, N>) -> Option<&T>
+where
+    N: Capacity<T>,
+{
+    vec.generic_position(|_| true).map(|i| &vec[i])
+}
+
+#[test]
+fn test_capacity_bound_suffices() {
+    let vec = GenericArrayVec::generic_from([3, 4]);
+
+    assert_eq!(first(&vec), Some(&3));
+}
+
+#[test]
+fn test_index_is_usize() {
+    assert_eq!(
+        size_of::<GenericArrayVec<u8, U4>>(),
+        size_of::<usize>() + size_of::<usize>()
+    );
+}